@@ -0,0 +1,140 @@
+use std::cmp::Ordering;
+use aoc_common::{Answer, ParseError, Scanner, ScanError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = (Vec<i32>, Vec<i32>);
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        partition(s).map_err(|e| e.locate(s))
+    }
+
+    fn part1(lists: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(lists).into())
+    }
+
+    fn part2(lists: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(lists).into())
+    }
+}
+
+fn part1(lists: &(Vec<i32>, Vec<i32>)) -> i32 {
+    let (list1, list2) = lists;
+    let sorted1 = mergesort(list1.clone());
+    let sorted2 = mergesort(list2.clone());
+    sum_of_diffs(sorted1, sorted2)
+}
+
+fn part2(lists: &(Vec<i32>, Vec<i32>)) -> i32 {
+    let (list1, list2) = lists;
+    let mut similarity = 0;
+    for x in list1.iter() {
+        similarity += *x * appearances_in(*x, list2);
+    }
+    similarity
+}
+
+fn partition(s: &str) -> Result<(Vec<i32>, Vec<i32>), ScanError> {
+    let mut list1: Vec<i32> = Vec::new();
+    let mut list2: Vec<i32> = Vec::new();
+    let mut sc = Scanner::new(s);
+    sc.skip_whitespace();
+    while !sc.at_end() {
+        list1.push(sc.num()?);
+        sc.skip_spaces();
+        list2.push(sc.num()?);
+        sc.skip_spaces();
+        if !sc.at_end() {sc.line_ending()?;}
+        sc.skip_whitespace();
+    }
+    Ok((list1, list2))
+}
+
+fn merge(l1: Vec<i32>, l2: Vec<i32>) -> Vec<i32> {
+    let mut comb: Vec<i32> = Vec::new();
+    let mut i1 = 0;
+    let mut i2 = 0;
+    loop {
+        match (l1.get(i1), l2.get(i2)) {
+            (None, None) => break,
+            (Some(k1), Some(k2)) => {
+                if k1 < k2 {
+                    comb.push(*k1);
+                    i1 += 1;
+                } else {
+                    comb.push(*k2);
+                    i2 += 1;
+                }
+            },
+            (Some(_), None) => {
+                comb.extend(l1[i1..].iter());
+                break;
+            },
+            (None, Some(_)) => {
+                comb.extend(l2[i2..].iter());
+                break;
+            }
+        }
+    }
+    comb
+}
+
+fn mergesort(l: Vec<i32>) -> Vec<i32> {
+    let len = l.len();
+    match len.cmp(&1) {
+        Ordering::Less => vec![],
+        Ordering::Equal => l,
+        Ordering::Greater => {
+            merge(mergesort(l[0..len/2].to_vec()), mergesort(l[len/2..len].to_vec()))
+        }
+    }
+}
+
+fn sum_of_diffs(list1: Vec<i32>, list2: Vec<i32>) -> i32 {
+    let mut total = 0;
+    for (i, x) in list1.iter().enumerate() {
+        if let Some(y) = list2.get(i) {
+            total += (y-x).abs();
+            continue;
+        }
+        panic!("sum_of_diffs call on unequal length vectors");
+    }
+    total
+}
+
+fn appearances_in(x: i32, l: &[i32]) -> i32 {
+    let mut app = 0;
+    for n in l.iter() {
+        if *n == x {
+            app += 1;
+        }
+    }
+    app
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn merge_() {
+        let l1 = vec![1, 4, 8, 12, 19];
+        let l2 = vec![2, 3, 5, 17, 19, 22];
+        assert_eq!(merge(l1, l2), vec![1, 2, 3, 4, 5, 8, 12, 17, 19, 19, 22])
+    }
+
+    #[test]
+    fn mergesort_() {
+        let l = vec![8, 3, 17, 5, 23, 18, 15, 17, 22, 3, 6];
+        assert_eq!(mergesort(l), vec![3, 3, 5, 6, 8, 15, 17, 17, 18, 22, 23])
+    }
+
+    #[test]
+    fn partition_() {
+        let s = "1234 5678\n876 543\n22 333\n";
+        assert_eq!(partition(s), Ok((vec![1234, 876, 22], vec![5678, 543, 333])));
+        assert_eq!(partition("1 2\n3 x\n").unwrap_err().locate("1 2\n3 x\n").column, 3);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,104 @@
+use aoc_common::{grid::Pos, Answer, Grid, ParseError, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Grid<u8>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        scan_to_map(s)
+    }
+
+    fn part1(map: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(map).into())
+    }
+
+    fn part2(map: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(map).into())
+    }
+}
+
+fn part1(map: &Grid<u8>) -> u32 {
+    let mut score = 0;
+    for (coord, dig) in map.iter() {
+        if *dig == 0 {
+            let mut reached = vec![coord];
+            for height in 1..=9 {
+                reached = find_next(map, &reached, height);
+            }
+            score += reached.len() as u32;
+        }
+    }
+    score
+}
+
+fn part2(map: &Grid<u8>) -> u32 {
+    let mut ratings = 0;
+    for (coord, dig) in map.iter() {
+        if *dig == 0 {
+            ratings += rating(map, coord);
+        }
+    }
+    ratings
+}
+
+fn scan_to_map(s: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(s, "a digit", |_, dig| dig.to_digit(10).map(|n| n as u8))
+}
+
+// Every square of `height` next to one of `from`, each listed once.
+fn find_next(map: &Grid<u8>, from: &[Pos], height: u8) -> Vec<Pos> {
+    let mut next: Vec<Pos> = Vec::new();
+    for &pos in from.iter() {
+        for neighbor in map.neighbors4(pos) {
+            if map[neighbor] == height && !next.contains(&neighbor) {next.push(neighbor);}
+        }
+    }
+    next
+}
+
+// The number of distinct trails from `from` up to a 9.
+fn rating(map: &Grid<u8>, from: Pos) -> u32 {
+    let height = map[from];
+    if height == 9 {return 1;}
+    map.neighbors4(from).filter(|&next| map[next] == height + 1).map(|next| rating(map, next)).sum()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn scan_to_map_() {
+        let map = scan_to_map("0123\n1234\n8765\n9876\n").unwrap();
+        assert_eq!(map.get((0, 0)), Some(&0));
+        assert_eq!(map.get((2, 1)), Some(&7));
+        assert_eq!(map.get((4, 2)), None);
+    }
+
+    #[test]
+    fn scan_to_map_err() {
+        let e = scan_to_map("0123\n12.4\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.found()), (2, 3, Some('.')));
+    }
+
+    #[test]
+    fn find_ones_() {
+        let map = scan_to_map("0123\n1234\n8765\n9876\n").unwrap();
+        let ones = find_next(&map, &[(0, 0)], 1);
+        assert_eq!(ones.len(), 2);
+        assert!(ones.contains(&(0, 1)));
+        assert!(ones.contains(&(1, 0)));
+    }
+
+    #[test]
+    fn find_twos_() {
+        let map = scan_to_map("0123\n1234\n8765\n9876\n").unwrap();
+        let ones = vec![(0, 1), (1, 0)];
+        let twos = find_next(&map, &ones, 2);
+        assert_eq!(twos.len(), 2);
+        assert!(twos.contains(&(1, 1)));
+        assert!(twos.contains(&(0, 2)));
+    }
+}
\ No newline at end of file
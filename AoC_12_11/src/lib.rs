@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+use aoc_common::{Answer, ParseError, Scanner, ScanError, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = HashMap<u64, u64>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        read_to_hashmap(s).map_err(|e| e.locate(s))
+    }
+
+    fn part1(stones: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(stones).into())
+    }
+
+    fn part2(stones: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(stones).into())
+    }
+}
+
+fn part1(stones: &HashMap<u64, u64>) -> u64 {
+    let mut stones = stones.clone();
+    for _ in 0..25 {
+        stones = blink(&stones);
+        // println!("{:?}", stones);
+    }
+    num_stones(&stones)
+}
+
+fn part2(stones: &HashMap<u64, u64>) -> u64 {
+    let mut stones = stones.clone();
+    for _ in 0..75 {
+        stones = blink(&stones);
+        // println!("{:?}", stones);
+    }
+    num_stones(&stones)
+}
+
+// fn read_to_vec(s: &str) -> Vec<u64> {
+//     let mut stones = vec![];
+//     for stone in s.trim().split(" ") {
+//         stones.push(stone.parse::<u64>().expect("parse error"));
+//     }
+//     stones
+// }
+
+fn read_to_hashmap(s: &str) -> Result<HashMap<u64, u64>, ScanError> {
+    let mut stones = HashMap::new();
+    let mut sc = Scanner::new(s);
+    sc.skip_whitespace();
+    while !sc.at_end() {
+        let val: u64 = sc.num()?;
+        add_stone(&mut stones, (val, 1));
+        sc.skip_whitespace();
+    }
+    Ok(stones)
+}
+
+fn digits(x: u64) -> u8 {
+    if x == 0 {0}
+    else {1 + digits(x/10)}
+}
+
+fn split(x: u64, n: u8) -> (u64, u64) {
+    (x / u64::pow(10, n as u32), x % u64::pow(10, n as u32))
+}
+
+fn blink(v: &HashMap<u64, u64>) -> HashMap<u64, u64> {
+    let mut new_map: HashMap<u64, u64> = HashMap::new();
+    for (stone, freq) in v.iter() {
+        if *stone == 0 {
+            add_stone(&mut new_map, (1, *freq));
+        } else {
+            let digs = digits(*stone);
+            if digs.is_multiple_of(2) {
+                let (left, right) = split(*stone, digs / 2);
+                add_stone(&mut new_map, (left, *freq));
+                add_stone(&mut new_map, (right, *freq));
+            } else {
+                add_stone(&mut new_map, (*stone * 2024, *freq));
+            }
+        }
+    }
+    new_map
+}
+
+fn add_stone(map: &mut HashMap<u64, u64>, stone: (u64, u64)) {
+    if let Some(s_ref) = map.get_mut(&stone.0) {
+        *s_ref += stone.1;
+    } else {
+        map.insert(stone.0, stone.1);
+    }
+}
+
+fn num_stones(stones: &HashMap<u64, u64>) -> u64 {
+    let mut num_stones = 0;
+    for (_, freq) in stones.iter() {
+        num_stones += *freq;
+    }
+    num_stones
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read_to_hashmap_() {
+        let v = read_to_hashmap("253 0 2024 14168").unwrap();
+        let expected = HashMap::from([(253, 1), (0, 1), (2024, 1), (14168, 1)]);
+        assert_eq!(v, expected);
+    }
+
+    #[test]
+    fn blink_() {
+        let mut v = HashMap::from([(253, 1), (0, 1), (2024, 1), (14168, 1)]);
+        let expected = HashMap::from([(512072, 1), (1, 1), (20, 1), (24, 1), (28676032, 1)]);
+        v = blink(&v);
+        assert_eq!(v, expected);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,310 @@
+use std::collections::{HashSet, VecDeque};
+use aoc_common::{grid::Pos, Answer, Grid, ParseError, Solution};
+
+#[derive(PartialEq, Debug, Clone)]
+struct Region {
+    letter: char,
+    coords: Vec<(usize, usize)>
+}
+
+impl Region {
+    fn from(letter: char, coords: Vec<(usize, usize)>) -> Self {
+        Self {
+            letter,
+            coords
+        }
+    }
+
+    fn price1(&self) -> usize {
+        self.area() * self.perimeter()
+    }
+
+    fn price2(&self) -> i32 {
+        self.area() as i32 * self.sides()
+    }
+
+    fn area(&self) -> usize {
+        self.coords.len()
+    }
+
+    fn perimeter(&self) -> usize {
+        let mut perimeter = self.area() * 4;
+        for (i, coord1) in self.coords.iter().enumerate() {
+            for coord2 in self.coords[i..].iter() {
+                if coord1.0 == coord2.0 && (coord1.1 == coord2.1 + 1 || coord1.1 + 1 == coord2.1) {
+                    perimeter -= 2;
+                }
+                if coord1.1 == coord2.1 && (coord1.0 == coord2.0 + 1 || coord1.0 + 1 == coord2.0) {
+                    perimeter -= 2;
+                }
+            }
+        }
+        perimeter
+    }
+
+    fn sides(&self) -> i32 {
+        let mut sides: i32 = 0;
+        let mut visited: Vec<(usize, usize)> = vec![];
+        for index in self.coords.iter() {
+            let cardinals = Self::cardinals(*index, &visited);
+            match cardinals.len() {
+                0 => {sides += 4;},
+                4 => {sides -= 4;},
+                1 => {
+                    sides += 2 * Self::adjacent_corners(*index, cardinals[0], &visited) as i32;
+                },
+                2 => {
+                    if Self::opposite(cardinals[0], cardinals[1]) {
+                        match Self::corners(*index, &visited) {
+                            0 => {sides -= 2;},
+                            x => {sides += (x as i32 - 2) * 2;}
+                        }
+                    } else {
+                        sides += (Self::side_corners(*index, &cardinals, &visited) as i32 - 1) * 2;
+                    }
+                },
+                3 => {sides -= (2 - Self::outside_corners(*index, &cardinals, &visited) as i32) * 2;},
+                _ => {panic!("sides call: >4 cardinals");}
+            }
+            visited.push(*index);
+        }
+        sides
+    }
+
+    fn cardinals(index: (usize, usize), visited: &[(usize, usize)]) -> Vec<(usize, usize)> {
+        let mut adjacent = vec![(index.0 + 1, index.1), (index.0, index.1 + 1)];
+        if index.0 > 0 {adjacent.push((index.0 - 1, index.1));}
+        if index.1 > 0 {adjacent.push((index.0, index.1 - 1));}
+        intersection(visited, &adjacent)
+    }
+
+    fn corners(index: (usize, usize), visited: &[(usize, usize)]) -> u8 {
+        let mut corners = visited.contains(&(index.0 + 1, index.1 + 1)) as u8;
+        if index.0 > 0 {corners += visited.contains(&(index.0 - 1, index.1 + 1)) as u8;}
+        if index.1 > 0 {corners += visited.contains(&(index.0 + 1, index.1 - 1)) as u8;}
+        if index.0 > 0 && index.1 > 0 {corners += visited.contains(&(index.0 - 1, index.1 - 1)) as u8;}
+        corners
+    }
+
+    fn adjacent_corners(index: (usize, usize), cardinal: (usize, usize), visited: &[(usize, usize)]) -> u8 {
+        if cardinal.0 == index.0 {
+            if index.0 > 0 {visited.contains(&(cardinal.0 - 1, cardinal.1)) as u8 + visited.contains(&(cardinal.0 + 1, cardinal.1)) as u8}
+            else {visited.contains(&(cardinal.0 + 1, cardinal.1)) as u8}
+        } else if cardinal.1 == index.1 {
+            if cardinal.1 > 0 {visited.contains(&(cardinal.0, cardinal.1 - 1)) as u8 + visited.contains(&(cardinal.0, cardinal.1 + 1)) as u8}
+            else {visited.contains(&(cardinal.0, cardinal.1 + 1)) as u8}
+        } else {panic!("adjacent_corners call: index and cardinal missing common coordinate");}
+    }
+
+    fn side_corners(index: (usize, usize), cardinals: &[(usize, usize)], visited: &[(usize, usize)]) -> u8 {
+        if cardinals.contains(&(index.0 + 1, index.1)) && cardinals.contains(&(index.0, index.1 + 1)) {
+            (index.0 > 0 && visited.contains(&(index.0 - 1, index.1 + 1))) as u8 + (index.1 > 0 && visited.contains(&(index.0 + 1, index.1 - 1))) as u8
+        } else if (index.0 > 0 && cardinals.contains(&(index.0 - 1, index.1)) && cardinals.contains(&(index.0, index.1 + 1)))
+            || (index.1 > 0 && cardinals.contains(&(index.0, index.1 - 1)) && cardinals.contains(&(index.0 + 1, index.1))) {
+            (index.0 > 0 && index.1 > 0 && visited.contains(&(index.0 - 1, index.1 - 1))) as u8 + (visited.contains(&(index.0 + 1, index.1 + 1))) as u8
+        } else if index.0 > 0 && index.1 > 0 && cardinals.contains(&(index.0 - 1, index.1)) && cardinals.contains(&(index.0, index.1 - 1)) {
+            (index.0 > 0 && visited.contains(&(index.0 - 1, index.1 + 1))) as u8 + (index.1 > 0 && visited.contains(&(index.0 + 1, index.1 - 1))) as u8
+        } else {panic!("side_corners call: invalid cardinals")}
+    }
+
+    fn outside_corners(index: (usize, usize), cardinals: &[(usize, usize)], visited: &[(usize, usize)]) -> u8 {
+        if !cardinals.contains(&(index.0 + 1, index.1)) {
+            (index.1 > 0 && visited.contains(&(index.0 + 1, index.1 - 1))) as u8 + visited.contains(&(index.0 + 1, index.1 + 1)) as u8
+        } else if !cardinals.contains(&(index.0, index.1 + 1)) {
+            (index.0 > 0 && visited.contains(&(index.0 - 1, index.1 + 1))) as u8 + visited.contains(&(index.0 + 1, index.1 + 1)) as u8
+        } else if index.0 == 0 || !cardinals.contains(&(index.0 - 1, index.1)) {
+            (index.0 > 0 && visited.contains(&(index.0 - 1, index.1 + 1))) as u8 + (index.0 > 0 && index.1 > 0 && visited.contains(&(index.0 - 1, index.1 - 1))) as u8
+        } else if index.1 == 0 || !cardinals.contains(&(index.0, index.1 - 1)) {
+            (index.1 > 0 && visited.contains(&(index.0 + 1, index.1 - 1))) as u8 + (index.0 > 0 && index.1 > 0 && visited.contains(&(index.0 - 1, index.1 - 1))) as u8
+        } else {panic!("outside_corners call: invalid cardinals");}
+    }
+
+    fn opposite(index1: (usize, usize), index2: (usize, usize)) -> bool {
+        if index1.0 == index2.0 && (index1.1 + 2 == index2.1 || index1.1 == index2.1 + 2) {return true;}
+        index1.1 == index2.1 && (index1.0 + 2 == index2.0 || index1.0 == index2.0 + 2)
+    }
+
+    #[allow(dead_code)]
+    fn to_hashset(&self) -> HashSet<(usize, usize)> {
+        let x = self.coords.clone();
+        HashSet::from_iter(x)
+    }
+
+    #[allow(dead_code)]
+    fn report(&self) {
+        let area = self.area();
+        let perimeter = self.perimeter();
+        let sides = self.sides();
+        assert!(perimeter as i32 >= sides);
+        match (area, area < 1) {
+            (_,true) => {panic!("area < 1");},
+            (1,_) => {
+                assert_eq!(perimeter, 4);
+                assert_eq!(sides, 4);
+            },
+            (2,_) => {
+                assert_eq!(perimeter, 6);
+                assert_eq!(sides, 4);
+            },
+            _ => {println!("'{}': area={}, perimeter={}, sides={}", self.letter, area, perimeter, sides);}
+        }        
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Grid<char>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        read_to_map(s)
+    }
+
+    fn part1(map: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(map).into())
+    }
+
+    fn part2(map: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(map).into())
+    }
+}
+
+fn part1(map: &Grid<char>) -> usize {
+    let mut price = 0;
+    let regions = get_all_regions(map);
+    for region in regions.iter() {
+        price += region.price1();
+    }
+    price
+}
+
+fn part2(map: &Grid<char>) -> i32 {
+    let mut price = 0;
+    let regions = get_all_regions(map);
+    for region in regions.iter() {
+        price += region.price2();
+    }
+    price
+}
+
+fn read_to_map(s: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(s, "a plant letter", |_, c| if c.is_ascii_uppercase() {Some(c)} else {None})
+}
+
+fn get_all_regions(map: &Grid<char>) -> Vec<Region> {
+    let mut regions: Vec<Region> = vec![];
+    let mut seen = map.map(|_| false);
+    for index in map.positions() {
+        if seen[index] {continue;}
+        regions.push(get_region(map, &mut seen, index));
+    }
+    regions
+}
+
+// Floods out from `index`, so every square after the first touches one
+// listed before it, which `Region::sides` relies on.
+fn get_region(map: &Grid<char>, seen: &mut Grid<bool>, index: Pos) -> Region {
+    let letter = map[index];
+    let mut reg_coords = vec![];
+    let mut queue = VecDeque::from([index]);
+    seen[index] = true;
+    while let Some(index) = queue.pop_front() {
+        reg_coords.push(index);
+        for next in map.neighbors4(index) {
+            if !seen[next] && map[next] == letter {
+                seen[next] = true;
+                queue.push_back(next);
+            }
+        }
+    }
+    Region::from(letter, reg_coords)
+}
+
+fn intersection(v1: &[(usize, usize)], v2: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let mut int: Vec<(usize, usize)> = vec![];
+    for elem in v1.iter() {
+        if v2.contains(elem) {int.push(*elem);}
+    }
+    int
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn letter_regions(map: &Grid<char>, letter: char) -> Vec<Region> {
+        get_all_regions(map).into_iter().filter(|r| r.letter == letter).collect()
+    }
+
+    #[test]
+    fn read_to_map_() {
+        let map = read_to_map("AABC\nAABD\nEEEA\nEEEA\n").unwrap();
+        assert_eq!((map.rows(), map.cols()), (4, 4));
+        assert_eq!(map[(0, 2)], 'B');
+        assert_eq!(map[(3, 3)], 'A');
+        assert_eq!(map.get((4, 0)), None);
+    }
+
+    #[test]
+    fn read_to_map_err() {
+        let e = read_to_map("AABC\nAA-D\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.found()), (2, 3, Some('-')));
+    }
+
+    #[test]
+    fn get_region_() {
+        let map = read_to_map("AABC\nAABD\nEEEA\nEEEA\n").unwrap();
+        let mut seen = map.map(|_| false);
+        let r = get_region(&map, &mut seen, (0, 0));
+        assert_eq!(HashSet::from_iter(r.coords), HashSet::from([(0, 0), (0, 1), (1, 0), (1, 1)]));
+        let r = get_region(&map, &mut seen, (3, 1));
+        assert_eq!(HashSet::from_iter(r.coords), HashSet::from([(2, 0), (2, 1), (2, 2), (3, 0), (3, 1), (3, 2)]));
+    }
+
+    #[test]
+    fn get_letter_regions_() {
+        let map = read_to_map("AABC\nAABD\nEEEA\nEEEA\n").unwrap();
+        let regions = letter_regions(&map, 'A');
+        assert_eq!(regions.len(), 2);
+        let expected = [HashSet::from([(0usize, 0usize), (0, 1), (1, 0), (1, 1)]), HashSet::from([(2, 3), (3, 3)])];
+        for r in regions.iter() {
+            assert!(expected.contains(&r.to_hashset()))
+        }
+    }
+
+    #[test]
+    fn get_all_regions_() {
+        let map = read_to_map("AABC\nAABD\nEEEA\nEEEA\n").unwrap();
+        let regions = get_all_regions(&map);
+        assert_eq!(regions.len(), 6);
+    }
+
+    #[test]
+    fn perimeter() {
+        let r = Region::from('A', Vec::from([(1, 1), (2, 1), (2, 2), (3, 1), (3, 2)]));
+        assert_eq!(r.perimeter(), 10);
+    }
+
+    #[test]
+    fn price1() {
+        let r = Region::from('A', Vec::from([(1, 1), (2, 1), (2, 2), (3, 1), (3, 2)]));
+        assert_eq!(r.price1(), 50);
+    }
+
+    #[test]
+    fn sides_() {
+        let map = read_to_map("
+AAEEEA
+AEEAEE
+AEEAAA
+AAEEEE
+AEEAAA
+AAAAAA
+").unwrap();
+        let e_regions = letter_regions(&map, 'E');
+        assert_eq!(e_regions.len(), 1);
+        println!("{:?}", e_regions[0]);
+        assert_eq!(e_regions[0].sides(), 20);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,253 @@
+use aoc_common::{Answer, ParseError, Scanner, ScanError, Solution};
+
+#[derive(Eq, Hash, PartialEq, Debug, Clone)]
+pub struct System {
+    eq1: (u64, u64, u64),
+    eq2: (u64, u64, u64),
+    soln: Option<(u64, u64)>
+}
+
+impl System {
+    fn new(eq1: (u64, u64, u64), eq2: (u64, u64, u64)) -> Self {
+        Self {
+            eq1,
+            eq2,
+            soln: None
+        }
+    }
+
+    fn calibrate(&mut self) {
+        self.eq1.2 += 10000000000000;
+        self.eq2.2 += 10000000000000;
+    }
+
+    // Cramer's rule in exact integers: press counts have to divide out evenly
+    // and not be negative. Buttons that push along the same line leave one
+    // equation, solved for the fewest tokens, as long as the other agrees.
+    fn solve(&mut self) {
+        self.soln = None;
+        let (a1, b1, p1) = (self.eq1.0 as i128, self.eq1.1 as i128, self.eq1.2 as i128);
+        let (a2, b2, p2) = (self.eq2.0 as i128, self.eq2.1 as i128, self.eq2.2 as i128);
+        let det = a1 * b2 - b1 * a2;
+        if det == 0 {
+            let (x, y, p) = if (a1, b1, p1) != (0, 0, 0) {(a1, b1, p1)} else {(a2, b2, p2)};
+            self.soln = cheapest(x, y, p)
+                .filter(|&(a, b)| a1 * a + b1 * b == p1 && a2 * a + b2 * b == p2)
+                .map(|(a, b)| (a as u64, b as u64));
+            return;
+        }
+        let a_num = p1 * b2 - b1 * p2;
+        let b_num = a1 * p2 - p1 * a2;
+        if a_num % det != 0 || b_num % det != 0 {return;}
+        let (a, b) = (a_num / det, b_num / det);
+        if a < 0 || b < 0 {return;}
+        self.soln = Some((a as u64, b as u64));
+    }
+
+    fn tokens(&self) -> u64 {
+        if let Some((a, b)) = self.soln {
+            3*a + b
+        } else {0}
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<System>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        read_systems(s).map_err(|e| e.locate(s))
+    }
+
+    fn part1(systems: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(systems).into())
+    }
+
+    fn part2(systems: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(systems).into())
+    }
+}
+
+fn part1(systems: &[System]) -> u64 {
+    let mut tokens = 0;
+    let mut systems = systems.to_vec();
+    for sys in systems.iter_mut() {
+        sys.solve();
+        tokens += sys.tokens();
+    }
+    tokens
+}
+
+fn part2(systems: &[System]) -> u64 {
+    let mut tokens = 0;
+    let mut systems = systems.to_vec();
+    for sys in systems.iter_mut() {
+        sys.calibrate();
+        sys.solve();
+        tokens += sys.tokens();
+    }
+    tokens
+}
+
+// The presses (a, b) with a*x + b*y = p that cost the fewest tokens. Every
+// answer is (a + k*y/g, b - k*x/g) for one of them, and each step of k
+// changes the cost by the same amount, so the cheapest is at one end.
+fn cheapest(x: i128, y: i128, p: i128) -> Option<(i128, i128)> {
+    if x == 0 && y == 0 {return (p == 0).then_some((0, 0));}
+    if x == 0 {return (p % y == 0).then_some((0, p / y));}
+    if y == 0 {return (p % x == 0).then_some((p / x, 0));}
+    let (g, u) = gcd_with_coefficient(x, y);
+    if p % g != 0 {return None;}
+    let step = y / g;
+    let fewest_a = (u * (p / g)).rem_euclid(step);
+    if fewest_a * x > p {return None;}
+    let a = if 3 * y >= x {fewest_a} else {fewest_a + (p / x - fewest_a) / step * step};
+    Some((a, (p - a * x) / y))
+}
+
+// gcd(x, y) and a u with x*u = gcd(x, y) (mod y).
+fn gcd_with_coefficient(x: i128, y: i128) -> (i128, i128) {
+    let (mut r0, mut r1) = (x, y);
+    let (mut u0, mut u1) = (1, 0);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (u0, u1) = (u1, u0 - q * u1);
+    }
+    (r0, u0)
+}
+
+fn read_systems(s: &str) -> Result<Vec<System>, ScanError> {
+    let mut systems: Vec<System> = Vec::new();
+    let mut sc = Scanner::new(s);
+    sc.skip_whitespace();
+    while !sc.at_end() {
+        sc.literal("Button A: X+")?;
+        let a1: u64 = sc.num()?;
+        sc.literal(", Y+")?;
+        let a2: u64 = sc.num()?;
+        sc.line_ending()?;
+
+        sc.literal("Button B: X+")?;
+        let b1: u64 = sc.num()?;
+        sc.literal(", Y+")?;
+        let b2: u64 = sc.num()?;
+        sc.line_ending()?;
+
+        sc.literal("Prize: X=")?;
+        let prize1: u64 = sc.num()?;
+        sc.literal(", Y=")?;
+        let prize2: u64 = sc.num()?;
+        sc.skip_whitespace();
+
+        systems.push(System::new((a1, b1, prize1), (a2, b2, prize2)));
+    }
+
+    Ok(systems)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn solve_good() {
+        let mut sys = System::new((94, 22, 8400), (34, 67, 5400));
+        sys.solve();
+        let expected = Some((80, 40));
+        assert_eq!(sys.soln, expected);
+    }
+
+    #[test]
+    fn solve_bad() {
+        let mut sys = System::new((26, 67, 12748), (66, 21, 12176));
+        sys.solve();
+        assert_eq!(sys.soln, None);
+    }
+
+    // Each of these fooled the old f64 solver and its 0.001 tolerance.
+    #[test]
+    fn solve_near_miss() {
+        // B = -0.35, whose fract() is below the tolerance.
+        let mut sys = System::new((57, 34, 12984), (50, 68, 11376));
+        sys.solve();
+        assert_eq!(sys.soln, None);
+
+        // The same after calibrating, with B = -0.1.
+        let mut sys = System::new((71, 80, 8261), (71, 90, 8260));
+        sys.calibrate();
+        sys.solve();
+        assert_eq!(sys.soln, None);
+
+        // And the other way: an exact solution that came out as rejected.
+        let mut sys = System::new((21, 65, 5410), (22, 63, 7177));
+        sys.calibrate();
+        sys.solve();
+        assert_eq!(sys.soln, Some((186915889025, 93457943629)));
+    }
+
+    #[test]
+    fn solve_parallel() {
+        let mut sys = System::new((2, 4, 10), (3, 6, 15));
+        sys.solve();
+        assert_eq!(sys.soln, Some((1, 2)));
+        assert_eq!(sys.tokens(), 5);
+
+        // A is worth pressing when it moves more than three B presses.
+        let mut sys = System::new((4, 1, 9), (8, 2, 18));
+        sys.solve();
+        assert_eq!(sys.soln, Some((2, 1)));
+
+        let mut sys = System::new((2, 4, 10), (3, 6, 16));
+        sys.solve();
+        assert_eq!(sys.soln, None);
+        let mut sys = System::new((2, 4, 9), (3, 6, 13));
+        sys.solve();
+        assert_eq!(sys.soln, None);
+        let mut sys = System::new((0, 0, 0), (0, 5, 15));
+        sys.solve();
+        assert_eq!(sys.soln, Some((0, 3)));
+    }
+
+    #[test]
+    fn read_systems_() {
+        let systems = read_systems("Button A: X+99, Y+37
+Button B: X+18, Y+26
+Prize: X=9441, Y=5051
+
+Button A: X+32, Y+49
+Button B: X+39, Y+13
+Prize: X=19007, Y=19244
+
+Button A: X+89, Y+60
+Button B: X+13, Y+73
+Prize: X=4445, Y=4731
+").unwrap();
+        let expected = Vec::from([System::new((99, 18, 9441), (37, 26, 5051)),
+            System::new((32, 39, 19007), (49, 13, 19244)), System::new((89, 13, 4445), (60, 73, 4731))]);
+        assert_eq!(systems, expected);
+    }
+
+    #[test]
+    fn read_systems_err() {
+        let s = "Button A: X+99, Y+37\nButton B: X+18, Y-26\nPrize: X=9441, Y=5051\n";
+        let e = read_systems(s).unwrap_err().locate(s);
+        assert_eq!((e.line, e.column, e.found()), (2, 18, Some('-')));
+    }
+
+    #[test]
+    fn calibrate() {
+        let mut tokens = read_systems(include_str!("../../examples/day13/claw.txt")).unwrap();
+        for token in tokens.iter_mut() {
+            token.calibrate();
+            token.solve();
+        }
+        let mut token_iter = tokens.iter();
+        assert_eq!(token_iter.next().unwrap().soln, None);
+        assert!(token_iter.next().unwrap().soln.is_some());
+        assert_eq!(token_iter.next().unwrap().soln, None);
+        assert!(token_iter.next().unwrap().soln.is_some());
+    }
+}
\ No newline at end of file
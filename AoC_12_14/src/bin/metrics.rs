@@ -0,0 +1,30 @@
+use std::{env, path::PathBuf, process::exit};
+use aoc_common::{runner::{default_source, read_source}, Solution};
+use aoc_12_14::{metrics, parse_with, Config, Day14};
+
+const USAGE: &str = "Usage: cargo run -p AoC_12_14 --bin metrics -- [FILE | -] [day 14 options]";
+
+// Prints frame metrics for every second of a full cycle as CSV.
+fn main() {
+    let (config, args) = Config::from_args(env::args().skip(1).collect()).unwrap_or_else(|e| {
+        eprintln!("{}\n{}\n{}", e, USAGE, aoc_12_14::USAGE);
+        exit(2);
+    });
+    let source = match args.as_slice() {
+        [] => default_source(Day14::DAY),
+        [source] if !source.starts_with("--") => source.clone(),
+        _ => {
+            eprintln!("{}\n{}", USAGE, aoc_12_14::USAGE);
+            exit(2);
+        }
+    };
+    let input = read_source(&source).unwrap_or_else(|e| {
+        eprintln!("Error: could not read '{}': {}", PathBuf::from(&source).display(), e);
+        exit(1);
+    });
+    let field = parse_with(&input, &config).unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        exit(1);
+    });
+    print!("{}", metrics::time_series_csv(&field));
+}
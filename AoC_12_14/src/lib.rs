@@ -0,0 +1,612 @@
+use std::collections::HashSet;
+use aoc_common::{render::{self, Frame, Tile}, runner::{self, read_source}, Answer, Grid, ParseError, ParseErrorKind, Scanner, ScanError, Solution};
+
+// Width and height of the puzzle's arena, and of the 11x7 one in its example.
+const FULL_SIZE: (i16, i16) = (101, 103);
+const EXAMPLE_SIZE: (i16, i16) = (11, 7);
+
+// The most seconds `Field::at` steps through one by one, which is a full
+// cycle of the puzzle's arena with room to spare.
+pub const STEP_LIMIT: u32 = 100_000;
+
+pub mod metrics;
+pub mod rules;
+
+pub use rules::{OnWall, Rules};
+
+pub const USAGE: &str = "Day 14 options, anywhere among the others:
+  --size WIDTHxHEIGHT   the arena's size, over any size= line and the guess from the robots
+  --walls FILE          a map of the arena with # for walls, which robots bounce off
+  --stop                robots stop at walls instead of bouncing
+  --merge               robots that end a second on one tile become one";
+
+// How to read and move the robots beyond what the input says.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    // Overrides a `size=` line in the input and the guess from the robots.
+    pub size: Option<(i16, i16)>,
+    pub rules: Rules
+}
+
+impl Config {
+    // Takes `--size WIDTHxHEIGHT`, `--walls FILE`, `--stop` and `--merge`
+    // from anywhere in `args` and returns the rest for the runner. Robots
+    // bounce off walls unless `--stop` is given.
+    pub fn from_args(args: Vec<String>) -> Result<(Self, Vec<String>), String> {
+        let mut config = Config::default();
+        let mut walls = None;
+        let mut stop = false;
+        let mut merge = false;
+        let rest = runner::take_day_flags(args, |flag, args| {
+            match flag {
+                "--size" => {
+                    config.size = Some(args.next().and_then(parse_size).ok_or("--size needs WIDTHxHEIGHT, such as 11x7")?);
+                },
+                "--walls" => {
+                    let path = args.next().ok_or("--walls needs a file with a map of the arena")?;
+                    let map = read_source(path).map_err(|e| format!("could not read '{}': {}", path, e))?;
+                    walls = Some(rules::read_walls(&map).map_err(|e| format!("in '{}': {}", path, e))?);
+                },
+                "--stop" => {stop = true;},
+                "--merge" => {merge = true;},
+                _ => {return Ok(false);}
+            }
+            Ok(true)
+        })?;
+        if merge {
+            config.rules = config.rules.with_merging();
+        }
+        match walls {
+            Some(walls) => {config.rules = config.rules.with_walls(walls, if stop {OnWall::Stop} else {OnWall::Bounce});},
+            None if stop => {return Err("--stop goes with --walls".to_string());},
+            None => {}
+        }
+        Ok((config, rest))
+    }
+}
+
+fn parse_size(s: &str) -> Option<(i16, i16)> {
+    let (width, height) = s.split_once('x')?;
+    let (width, height) = (width.parse::<i16>().ok()?, height.parse::<i16>().ok()?);
+    (width > 0 && height > 0).then_some((width, height))
+}
+
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone)]
+pub struct Robot {
+    loc: (i16, i16),
+    vel: (i16, i16)
+}
+
+impl Robot {
+    fn new(loc: (i16, i16), vel: (i16, i16)) -> Self {
+        Self {
+            loc,
+            vel
+        }
+    }
+
+    fn elapse(&mut self, width: i16, height: i16) {
+        self.loc.0 = (self.loc.0 + self.vel.0).rem_euclid(width);
+        self.loc.1 = (self.loc.1 + self.vel.1).rem_euclid(height);
+    }
+
+    // Where the robot is `t` seconds from its start, which may be before it
+    // (negative t) or any number of cycles later.
+    pub fn position_at(&self, t: i64, width: i16, height: i16) -> (i16, i16) {
+        let wrap = |p: i16, v: i16, size: i16| {
+            let size = size as i64;
+            let steps = t.rem_euclid(size);
+            (p as i64 + v as i64 * steps).rem_euclid(size) as i16
+        };
+        (wrap(self.loc.0, self.vel.0, width), wrap(self.loc.1, self.vel.1, height))
+    }
+
+    fn has_neighbor(&self, locs: &HashSet<(i16, i16)>) -> bool {
+        let (x, y) = self.loc;
+        let neighbors = [(x-1,y-1),(x-1,y),(x-1,y+1),(x,y+1),(x+1,y+1),(x+1,y),(x+1,y-1),(x,y-1)];
+        neighbors.iter().any(|loc| locs.contains(loc))
+    }
+}
+
+// The robots, the arena they wrap around and any rules beyond wrapping.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Field {
+    width: i16,
+    height: i16,
+    robots: Vec<Robot>,
+    rules: Rules
+}
+
+impl Field {
+    fn new(width: i16, height: i16, robots: Vec<Robot>) -> Self {
+        Self {
+            width,
+            height,
+            robots,
+            rules: Rules::default()
+        }
+    }
+
+    pub fn with_rules(mut self, rules: Rules) -> Result<Self, String> {
+        rules.check(self.width, self.height, &self.robots).map_err(|(_, e)| e)?;
+        self.rules = rules;
+        Ok(self)
+    }
+
+    // Robots that all start inside the example's arena are taken to be the
+    // example; anything else gets the full size.
+    fn infer(robots: Vec<Robot>) -> Self {
+        let fits = robots.iter().all(|r| r.loc.0 < EXAMPLE_SIZE.0 && r.loc.1 < EXAMPLE_SIZE.1);
+        let (width, height) = if fits {EXAMPLE_SIZE} else {FULL_SIZE};
+        Field::new(width, height, robots)
+    }
+
+    fn elapse(&mut self) {
+        if !self.rules.is_plain() {
+            self.rules.elapse(self.width, self.height, &mut self.robots);
+            return;
+        }
+        for robot in self.robots.iter_mut() {
+            robot.elapse(self.width, self.height);
+        }
+    }
+
+    // Where the robots are `t` seconds from now, in the order they were
+    // read. Robots that merged into another are gone, so after merging the
+    // list can be shorter than the input. None when `at` has no field.
+    pub fn snapshot(&self, t: i64) -> Option<Vec<(i16, i16)>> {
+        self.at(t).map(|field| field.robots.iter().map(|r| r.loc).collect())
+    }
+
+    // The field as it is `t` seconds from now, which may be in the past.
+    // Walls and merging cannot be run backwards and are stepped a second at a
+    // time, so with those a negative `t` gives None, as does one more than
+    // STEP_LIMIT seconds ahead.
+    pub fn at(&self, t: i64) -> Option<Field> {
+        if self.rules.is_plain() {return Some(self.plain_at(t));}
+        match u32::try_from(t) {
+            Ok(seconds) if seconds <= STEP_LIMIT => Some(self.after(seconds)),
+            _ => None
+        }
+    }
+
+    // The field `seconds` from now, stepped there if the rules need it.
+    pub fn after(&self, seconds: u32) -> Field {
+        if self.rules.is_plain() {return self.plain_at(seconds as i64);}
+        let mut field = self.clone();
+        for _ in 0..seconds {
+            field.elapse();
+        }
+        field
+    }
+
+    fn plain_at(&self, t: i64) -> Field {
+        let robots = self.robots.iter().map(|r| Robot::new(r.position_at(t, self.width, self.height), r.vel)).collect();
+        Field::new(self.width, self.height, robots)
+    }
+
+    // Numbered clockwise from the top left; robots on a middle line are in
+    // none.
+    fn quadrant(&self, robot: &Robot) -> Option<u8> {
+        let (mid_x, mid_y) = (self.width / 2, self.height / 2);
+        let (x, y) = robot.loc;
+        if x < mid_x && y < mid_y {Some(0)}
+        else if x < mid_x && y > mid_y {Some(1)}
+        else if x > mid_x && y > mid_y {Some(2)}
+        else if x > mid_x && y < mid_y {Some(3)}
+        else {None}
+    }
+
+    fn safety_factor(&self) -> u64 {
+        let mut q0 = 0;
+        let mut q1 = 0;
+        let mut q2 = 0;
+        let mut q3 = 0;
+        for robot in self.robots.iter() {
+            match self.quadrant(robot) {
+                Some(0) => {q0 += 1;},
+                Some(1) => {q1 += 1;},
+                Some(2) => {q2 += 1;},
+                Some(3) => {q3 += 1;},
+                _ => {}
+            }
+        }
+        q0*q1*q2*q3
+    }
+
+    // Each robot tile shows how many robots share it.
+    fn frame(&self) -> Frame {
+        let mut counts = Grid::new(self.height as usize, self.width as usize, 0u32);
+        for robot in self.robots.iter() {
+            counts[(robot.loc.1 as usize, robot.loc.0 as usize)] += 1;
+        }
+        let mut frame = counts.map(|&n| match n {
+            0 => Tile::default(),
+            n => Tile::new(char::from_digit(n, 10).unwrap_or('+'), render::GREEN)
+        });
+        for (row, col) in counts.positions() {
+            if self.rules.is_wall((col as i16, row as i16)) {frame[(row, col)] = Tile::new('#', render::WALL);}
+        }
+        frame
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Field;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        parse_with(s, &Config::default())
+    }
+
+    fn part1(field: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(field).into())
+    }
+
+    fn part2(field: &Self::Input) -> Result<Answer, String> {
+        part2(field).map(Answer::from)
+    }
+
+    // The robots after 100 seconds for part 1, and at the tree for part 2,
+    // if there is one.
+    fn render(field: &Self::Input, part: u8) -> Option<Frame> {
+        Some(field.after(seconds_shown(field, part)?).frame())
+    }
+
+    // Every second up to the picture `render` shows.
+    fn animate(field: &Self::Input, part: u8, emit: &mut dyn FnMut(&Frame)) -> bool {
+        let Some(seconds) = seconds_shown(field, part) else {return false;};
+        let mut field = field.clone();
+        emit(&field.frame());
+        for _ in 0..seconds {
+            field.elapse();
+            emit(&field.frame());
+        }
+        true
+    }
+}
+
+// The arena's size comes from `config`, then a `size=WIDTHxHEIGHT` first
+// line, then a guess from where the robots are.
+pub fn parse_with(s: &str, config: &Config) -> Result<Field, ParseError> {
+    let mut sc = Scanner::new(s);
+    let header = read_size(&mut sc).map_err(|e| e.locate(s))?;
+    let robots = read_robots(&mut sc).map_err(|e| e.locate(s))?;
+    let invalid = |pos: usize, reason: String| ParseError::at(s, pos, ParseErrorKind::Invalid { reason });
+    if let Some((pos, width, height)) = header {
+        if width <= 0 || height <= 0 {return Err(invalid(pos, format!("the arena cannot be {}x{}", width, height)));}
+    }
+    let starts: Vec<usize> = robots.iter().map(|&(pos, _)| pos).collect();
+    let robots = robots.into_iter().map(|(_, robot)| robot).collect();
+    let field = match config.size.or(header.map(|(_, width, height)| (width, height))) {
+        Some((width, height)) => Field::new(width, height, robots),
+        None => Field::infer(robots)
+    };
+    let outside = field.robots.iter().position(|r| r.loc.0 < 0 || r.loc.1 < 0 || r.loc.0 >= field.width || r.loc.1 >= field.height);
+    if let Some(i) = outside {
+        return Err(invalid(starts[i], format!("robot starts outside the {}x{} arena", field.width, field.height)));
+    }
+    config.rules.check(field.width, field.height, &field.robots).map_err(|(robot, e)| invalid(robot.map_or(0, |i| starts[i]), e))?;
+    Ok(Field { rules: config.rules.clone(), ..field })
+}
+
+fn seconds_shown(field: &Field, part: u8) -> Option<u32> {
+    match part {
+        1 => Some(100),
+        _ => part2(field).ok()
+    }
+}
+
+fn part1(field: &Field) -> u64 {
+    field.after(100).safety_factor()
+}
+
+// Without a detection it trusts, part 2 falls back to searching for a frame
+// where most robots touch another.
+fn part2(field: &Field) -> Result<u32, String> {
+    match detect_tree(field) {
+        Some(found) if found.confidence > 0.0 => Ok(found.seconds),
+        _ => neighbor_search(field)
+    }
+}
+
+// The frame where the robots draw the tree, and how far that frame stands
+// out: 0 when it is no further from the others than chance would put one of
+// them, towards 1 as the robots pull together.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Detection {
+    pub seconds: u32,
+    pub confidence: f64
+}
+
+// The tree packs the robots together, so x positions are least spread out at
+// some time tx within each `width` seconds and y positions at some ty within
+// each `height` seconds. The tree is at the one time that is both, which the
+// Chinese remainder theorem gives when the sizes are coprime. Walls and
+// merging break the periods, so then there is no detection.
+pub fn detect_tree(field: &Field) -> Option<Detection> {
+    if !field.rules.is_plain() || field.robots.is_empty() || gcd(field.width as u32, field.height as u32) != 1 {return None;}
+    let (tx, x_confidence) = tightest(field, field.width, |r| r.0);
+    let (ty, y_confidence) = tightest(field, field.height, |r| r.1);
+    let (width, height) = (field.width as u32, field.height as u32);
+    let k = (0..height).find(|k| (tx + width * k) % height == ty)?;
+    Some(Detection {
+        seconds: tx + width * k,
+        confidence: x_confidence.min(y_confidence)
+    })
+}
+
+// The time within one period where `axis` varies least, and how far below
+// the other times' variances it is. Those spread roughly normally, and the
+// lowest of n normal samples is typically about sqrt(2 ln n) deviations below
+// their mean, so only a minimum further out than that is more than chance.
+// Confidence is 0 up to there and approaches 1 beyond it.
+fn tightest(field: &Field, period: i16, axis: impl Fn((i16, i16)) -> i16) -> (u32, f64) {
+    let variances: Vec<f64> = (0..period as u32).map(|t| {
+        let values: Vec<f64> = field.after(t).robots.iter().map(|r| axis(r.loc) as f64).collect();
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        values.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / values.len() as f64
+    }).collect();
+    let (t, min) = variances.iter().copied().enumerate().fold((0, f64::INFINITY), |best, (t, v)| if v < best.1 {(t, v)} else {best});
+    let rest: Vec<f64> = variances.iter().enumerate().filter(|&(i, _)| i != t).map(|(_, &v)| v).collect();
+    if rest.len() < 2 {return (t as u32, 0.0);}
+    let mean = rest.iter().sum::<f64>() / rest.len() as f64;
+    let deviation = (rest.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / rest.len() as f64).sqrt();
+    let chance = (2.0 * (variances.len() as f64).ln()).sqrt();
+    let confidence = match deviation {
+        0.0 if min < mean => 1.0,
+        0.0 => 0.0,
+        _ => (1.0 - chance * deviation / (mean - min)).max(0.0)
+    };
+    (t as u32, confidence)
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 {a} else {gcd(b, a % b)}
+}
+
+// Positions repeat every width * height seconds, so that is as far as the
+// tree can be.
+fn neighbor_search(field: &Field) -> Result<u32, String> {
+    let mut field = field.clone();
+    for n in 0..field.width as u32 * field.height as u32 {
+        if neighbor_ratio(&field.robots) > 0.7 {
+            return Ok(n);
+        }
+        field.elapse();
+    }
+    Err("no frame has most robots next to another, so there is no tree".to_string())
+}
+
+// An optional first line `size=WIDTHxHEIGHT`, and where it starts.
+fn read_size(sc: &mut Scanner) -> Result<Option<(usize, i16, i16)>, ScanError> {
+    sc.skip_whitespace();
+    let start = sc.pos();
+    if !sc.rest().starts_with("size=") {return Ok(None);}
+    sc.literal("size=")?;
+    let width: i16 = sc.num()?;
+    sc.char('x')?;
+    let height: i16 = sc.num()?;
+    sc.skip_spaces();
+    sc.line_ending()?;
+    Ok(Some((start, width, height)))
+}
+
+// Each robot with the position of its `p=`, for errors found later.
+fn read_robots(sc: &mut Scanner) -> Result<Vec<(usize, Robot)>, ScanError> {
+    let mut robots: Vec<(usize, Robot)> = vec![];
+    sc.skip_whitespace();
+    while !sc.at_end() {
+        let start = sc.pos();
+        sc.literal("p=")?;
+        let x_coord: i16 = sc.signed()?;
+        sc.char(',')?;
+        let y_coord: i16 = sc.signed()?;
+        sc.literal(" v=")?;
+        let x_vel: i16 = sc.signed()?;
+        sc.char(',')?;
+        let y_vel: i16 = sc.signed()?;
+        sc.skip_whitespace();
+        robots.push((start, Robot::new((x_coord, y_coord), (x_vel, y_vel))));
+    }
+    Ok(robots)
+}
+
+fn neighbor_ratio(robots: &[Robot]) -> f64 {
+    let locs: HashSet<(i16, i16)> = robots.iter().map(|r| r.loc).collect();
+    let mut neighbors = 0.0;
+    for robot in robots.iter() {
+        if robot.has_neighbor(&locs) {neighbors += 1.0;}
+    }
+    neighbors / robots.len() as f64
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read_robots_() {
+        let robots = read_robots(&mut Scanner::new("p=84,26 v=99,-23
+p=98,17 v=-41,-46
+p=3,84 v=-17,-23
+")).unwrap();
+        assert_eq!(robots.iter().map(|&(pos, _)| pos).collect::<Vec<_>>(), vec![0, 17, 35]);
+        let set: HashSet<(i16, i16)> = HashSet::from_iter(robots.iter().map(|(_, r)| r.loc));
+        let expected: HashSet<(i16, i16)> = HashSet::from([(84, 26), (98, 17), (3, 84)]);
+        assert_eq!(set, expected);
+    }
+
+    #[test]
+    fn read_robots_err() {
+        let s = "p=84,26 v=99,-23\np=98;17 v=-41,-46\n";
+        let e = read_robots(&mut Scanner::new(s)).unwrap_err().locate(s);
+        assert_eq!((e.line, e.column, e.found()), (2, 5, Some(';')));
+    }
+
+    #[test]
+    fn elapse_3() {
+        let mut robots = [Robot::new((84, 26), (99, -23)), Robot::new((98, 17), (-41, -46)), Robot::new((3, 84), (17, -23))];
+        for robot in robots.iter_mut() {
+            robot.elapse(101, 103);
+            robot.elapse(101, 103);
+            robot.elapse(101, 103);
+        }
+        let set: HashSet<(i16, i16)> = HashSet::from_iter(robots.iter().map(|r| r.loc));
+        let expected: HashSet<(i16, i16)> = HashSet::from([(78, 60), (76, 85), (54, 15)]);
+        assert_eq!(set, expected);
+    }
+
+    #[test]
+    fn safety_factor_() {
+        let robots = vec![Robot::new((84, 26), (99, -23)), Robot::new((18, 17), (-41, -46)), Robot::new((3, 44), (17, -23)), Robot::new((3, 84), (17, -23)), Robot::new((93, 84), (17, -23))];
+        assert_eq!(Field::new(101, 103, robots).safety_factor(), 2);
+    }
+
+    #[test]
+    fn example() {
+        let field = Day14::parse(include_str!("../../examples/day14/example.txt")).unwrap();
+        assert_eq!((field.width, field.height), EXAMPLE_SIZE);
+        let mut after = field.clone();
+        for _ in 0..100 {
+            after.elapse();
+        }
+        let picture = "......2..1.\n...........\n1..........\n.11........\n.....1.....\n...12......\n.1....1....\n";
+        assert_eq!(render::text(&after.frame()), picture.replace('.', " "));
+        assert_eq!(after.robots.iter().filter_map(|r| after.quadrant(r)).fold([0; 4], |mut q, i| {q[i as usize] += 1; q}), [1, 4, 1, 3]);
+        assert_eq!(part1(&field), 12);
+    }
+
+    #[test]
+    fn elapse_example() {
+        let mut robot = Robot::new((2, 4), (2, -3));
+        let expected = [(4, 1), (6, 5), (8, 2), (10, 6), (1, 3)];
+        for loc in expected {
+            robot.elapse(11, 7);
+            assert_eq!(robot.loc, loc);
+        }
+    }
+
+    #[test]
+    fn position_at() {
+        let robot = Robot::new((2, 4), (2, -3));
+        assert_eq!(robot.position_at(0, 11, 7), (2, 4));
+        assert_eq!(robot.position_at(5, 11, 7), (1, 3));
+        assert_eq!(robot.position_at(5 + 77 * 1000, 11, 7), (1, 3));
+        assert_eq!(robot.position_at(-1, 11, 7), (0, 0));
+        let mut stepped = robot.clone();
+        for t in 1..200 {
+            stepped.elapse(11, 7);
+            assert_eq!(robot.position_at(t, 11, 7), stepped.loc);
+            assert_eq!(Robot::new(stepped.loc, stepped.vel).position_at(-t, 11, 7), robot.loc);
+        }
+    }
+
+    #[test]
+    fn snapshot() {
+        let field = Day14::parse(include_str!("../../examples/day14/example.txt")).unwrap();
+        let mut stepped = field.clone();
+        for _ in 0..100 {
+            stepped.elapse();
+        }
+        assert_eq!(field.snapshot(100), Some(stepped.robots.iter().map(|r| r.loc).collect::<Vec<_>>()));
+        assert_eq!(field.at(100), Some(stepped.clone()));
+        assert_eq!(field.after(100), stepped);
+        assert_eq!(field.snapshot(100 - 77), field.snapshot(100));
+        assert_eq!(stepped.at(-100), Some(field.clone()));
+        assert_eq!(field.snapshot(i64::MIN).map(|locs| locs.len()), Some(12));
+    }
+
+    // Robots with scattered velocities, like the puzzle's, that gather in a
+    // 3x2 block at `t`.
+    fn gathering(width: i16, height: i16, t: i64) -> Field {
+        let robots = (0..30).map(|i| Robot::new((width / 2 + i % 3 - 1, height / 2 + i % 2), (i * 37 % 97 + 2, i * 53 % 89 - 44))).collect();
+        Field::new(width, height, robots).plain_at(-t)
+    }
+
+    #[test]
+    fn detect_tree_() {
+        let field = gathering(101, 103, 6000);
+        let found = detect_tree(&field).unwrap();
+        assert_eq!(found.seconds, 6000);
+        assert!(found.confidence > 0.5);
+        assert_eq!(part2(&field), Ok(6000));
+        // The example's 12 robots never stand out from chance.
+        let example = Day14::parse(include_str!("../../examples/day14/example.txt")).unwrap();
+        assert_eq!(detect_tree(&example).map(|found| found.confidence), Some(0.0));
+        assert!(neighbor_search(&Field::new(11, 7, vec![])).is_err());
+        assert_eq!(detect_tree(&gathering(11, 7, 40)).unwrap().seconds, 40);
+        assert_eq!(detect_tree(&gathering(10, 6, 4)), None);
+    }
+
+    #[test]
+    fn parse_outside() {
+        let e = Day14::parse("p=0,4 v=3,-3\np=200,2 v=-1,-3\n").unwrap_err();
+        assert_eq!(e.line, 2);
+        let e = Day14::parse("\n\np=0,4 v=3,-3\n\n   p=200,2 v=-1,-3\n").unwrap_err();
+        assert_eq!((e.line, e.column), (5, 4));
+    }
+
+    #[test]
+    fn parse_size() {
+        let s = "size=7x5\np=6,4 v=1,1\n";
+        assert_eq!(Day14::parse(s).map(|f| (f.width, f.height)), Ok((7, 5)));
+        let config = Config { size: Some((101, 103)), ..Config::default() };
+        assert_eq!(parse_with(s, &config).map(|f| (f.width, f.height)), Ok((101, 103)));
+        assert!(Day14::parse("size=7x0\np=0,0 v=1,1\n").is_err());
+        assert_eq!(Day14::parse("size=7x5\np=7,4 v=1,1\n").unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn parse_with_walls() {
+        let walls = rules::read_walls("..#\n...\n").unwrap();
+        let config = Config { size: None, rules: Rules::new().with_walls(walls, OnWall::Bounce) };
+        let e = parse_with("size=3x2\np=0,0 v=1,1\np=2,0 v=1,1\n", &config).unwrap_err();
+        assert_eq!((e.line, e.column), (3, 1));
+        assert!(e.to_string().contains("robot 2 starts inside a wall"));
+        assert!(parse_with("size=4x2\np=0,0 v=1,1\n", &config).is_err());
+        assert!(parse_with("size=3x2\np=0,1 v=1,1\n", &config).is_ok());
+    }
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn from_args() {
+        let (config, rest) = Config::from_args(args(&["input.txt", "--part", "1", "--size", "11x7"])).unwrap();
+        assert_eq!(config.size, Some((11, 7)));
+        assert_eq!(rest, args(&["input.txt", "--part", "1"]));
+        let (config, rest) = Config::from_args(args(&["--json", "--size", "--size", "11x7"])).unwrap();
+        assert_eq!(config.size, Some((11, 7)));
+        assert_eq!(rest, args(&["--json", "--size"]));
+        assert!(Config::from_args(args(&["--size", "11by7"])).is_err());
+        let (config, rest) = Config::from_args(args(&["in.txt", "--merge", "--size", "7x3"])).unwrap();
+        assert_eq!(config, Config { size: Some((7, 3)), rules: Rules::new().with_merging() });
+        assert_eq!(rest, args(&["in.txt"]));
+        assert!(Config::from_args(args(&["in.txt", "--stop"])).is_err());
+    }
+
+    #[test]
+    fn frame_() {
+        let field = Field::new(101, 103, vec![Robot::new((3, 1), (0, 0)), Robot::new((3, 1), (1, 1)), Robot::new((100, 102), (0, 0))]);
+        let frame = field.frame();
+        assert_eq!((frame.rows(), frame.cols()), (103, 101));
+        assert_eq!(frame[(1, 3)], Tile::new('2', render::GREEN));
+        assert_eq!(frame[(102, 100)].glyph, '1');
+        assert_eq!(frame[(0, 0)], Tile::default());
+    }
+
+    #[test]
+    fn animate() {
+        let field = Field::new(101, 103, vec![Robot::new((0, 0), (1, 2))]);
+        let mut locs = vec![];
+        assert!(Day14::animate(&field, 1, &mut |f| locs.push(f.iter().find(|(_, t)| t.glyph == '1').map(|(pos, _)| pos))));
+        assert_eq!(locs.len(), 101);
+        assert_eq!(locs[1], Some((2, 1)));
+        assert_eq!(locs[100], Some((200 % 103, 100)));
+    }
+}
@@ -0,0 +1,127 @@
+use std::{collections::VecDeque, fmt::Write as _};
+use aoc_common::Grid;
+use crate::Field;
+
+// Numbers that describe one frame, for spotting unusual ones.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Metrics {
+    pub quadrants: [usize; 4],
+    // Robots in the biggest group of occupied tiles joined side to side.
+    pub largest_cluster: usize,
+    pub rows: Vec<usize>,
+    pub cols: Vec<usize>,
+    // Shannon entropy of the row and column histograms, averaged and scaled
+    // so that robots spread evenly score 1 and robots in one tile score 0.
+    pub entropy: f64
+}
+
+impl Metrics {
+    pub fn of(field: &Field) -> Self {
+        let mut counts = Grid::new(field.height as usize, field.width as usize, 0usize);
+        let mut quadrants = [0; 4];
+        for robot in field.robots.iter() {
+            counts[(robot.loc.1 as usize, robot.loc.0 as usize)] += 1;
+            if let Some(q) = field.quadrant(robot) {quadrants[q as usize] += 1;}
+        }
+        let mut rows = vec![0; counts.rows()];
+        let mut cols = vec![0; counts.cols()];
+        for ((row, col), &n) in counts.iter() {
+            rows[row] += n;
+            cols[col] += n;
+        }
+        let entropy = (normalized_entropy(&rows) + normalized_entropy(&cols)) / 2.0;
+        Self {
+            quadrants,
+            largest_cluster: largest_cluster(&counts),
+            rows,
+            cols,
+            entropy
+        }
+    }
+}
+
+fn largest_cluster(counts: &Grid<usize>) -> usize {
+    let mut seen = Grid::new(counts.rows(), counts.cols(), false);
+    let mut largest = 0;
+    for (start, &n) in counts.iter() {
+        if n == 0 || seen[start] {continue;}
+        seen[start] = true;
+        let mut size = 0;
+        let mut queue = VecDeque::from([start]);
+        while let Some(pos) = queue.pop_front() {
+            size += counts[pos];
+            for next in counts.neighbors4(pos) {
+                if counts[next] > 0 && !seen[next] {
+                    seen[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        largest = largest.max(size);
+    }
+    largest
+}
+
+fn normalized_entropy(histogram: &[usize]) -> f64 {
+    let total: usize = histogram.iter().sum();
+    if total == 0 || histogram.len() < 2 {return 0.0;}
+    let bits: f64 = histogram.iter().filter(|&&n| n > 0).map(|&n| {
+        let p = n as f64 / total as f64;
+        -p * p.log2()
+    }).sum();
+    bits / (histogram.len() as f64).log2()
+}
+
+// One row per second for width * height seconds. With plain wrapping that is
+// a full cycle, after which every frame repeats. Walls and merging need not
+// repeat on that period, so with those it is only a window.
+pub fn time_series_csv(field: &Field) -> String {
+    let mut csv = "t,q0,q1,q2,q3,safety_factor,largest_cluster,busiest_row,busiest_col,entropy\n".to_string();
+    let mut field = field.clone();
+    for t in 0..field.width as u32 * field.height as u32 {
+        let m = Metrics::of(&field);
+        field.elapse();
+        let [q0, q1, q2, q3] = m.quadrants;
+        let busiest_row = m.rows.iter().max().copied().unwrap_or(0);
+        let busiest_col = m.cols.iter().max().copied().unwrap_or(0);
+        let _ = writeln!(csv, "{},{},{},{},{},{},{},{},{},{:.4}",
+            t, q0, q1, q2, q3, q0 * q1 * q2 * q3, m.largest_cluster, busiest_row, busiest_col, m.entropy);
+    }
+    csv
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Robot;
+
+    fn field(locs: &[(i16, i16)]) -> Field {
+        Field::new(11, 7, locs.iter().map(|&loc| Robot::new(loc, (1, 1))).collect())
+    }
+
+    #[test]
+    fn metrics() {
+        let m = Metrics::of(&field(&[(0, 0), (1, 0), (1, 1), (1, 1), (9, 5), (5, 3)]));
+        assert_eq!(m.quadrants, [4, 0, 1, 0]);
+        assert_eq!(m.largest_cluster, 4);
+        assert_eq!(m.rows, vec![2, 2, 0, 1, 0, 1, 0]);
+        assert_eq!(m.cols.iter().sum::<usize>(), 6);
+        assert_eq!(m.cols[1], 3);
+    }
+
+    #[test]
+    fn entropy() {
+        assert_eq!(Metrics::of(&field(&[(3, 3), (3, 3)])).entropy, 0.0);
+        assert_eq!(normalized_entropy(&[2, 2, 2, 2]), 1.0);
+        let spread = field(&(0..7).map(|i| (i, i)).collect::<Vec<_>>());
+        let packed = field(&(0..7).map(|i| (i % 2, i % 2)).collect::<Vec<_>>());
+        assert!(Metrics::of(&spread).entropy > Metrics::of(&packed).entropy);
+    }
+
+    #[test]
+    fn time_series() {
+        let csv = time_series_csv(&field(&[(0, 0), (10, 6)]));
+        assert_eq!(csv.lines().count(), 1 + 77);
+        assert_eq!(csv.lines().nth(1), Some("0,1,0,1,0,0,1,1,1,0.3226"));
+    }
+}
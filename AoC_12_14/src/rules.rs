@@ -0,0 +1,169 @@
+use std::collections::HashSet;
+use aoc_common::{Grid, ParseError};
+use crate::Robot;
+
+// What a robot does when its next tile is a wall.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnWall {
+    // Turns around along that axis and keeps going.
+    Bounce,
+    // Stays where it is from then on.
+    Stop
+}
+
+// How robots move beyond the puzzle's plain wrapping. The default adds
+// nothing, which keeps positions computable for any time.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Rules {
+    walls: Option<(Grid<bool>, OnWall)>,
+    merge: bool
+}
+
+impl Rules {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_walls(mut self, walls: Grid<bool>, on_wall: OnWall) -> Self {
+        self.walls = Some((walls, on_wall));
+        self
+    }
+
+    // Robots that end a second on the same tile become the first of them.
+    pub fn with_merging(mut self) -> Self {
+        self.merge = true;
+        self
+    }
+
+    pub fn is_plain(&self) -> bool {
+        self.walls.is_none() && !self.merge
+    }
+
+    pub fn is_wall(&self, loc: (i16, i16)) -> bool {
+        self.walls.as_ref().is_some_and(|(walls, _)| walls[(loc.1 as usize, loc.0 as usize)])
+    }
+
+    // Checks the walls cover the arena and no robot starts inside one. An
+    // error comes with the index of the robot at fault, if there is one.
+    pub(crate) fn check(&self, width: i16, height: i16, robots: &[Robot]) -> Result<(), (Option<usize>, String)> {
+        let Some((walls, _)) = &self.walls else {return Ok(());};
+        if (walls.cols(), walls.rows()) != (width as usize, height as usize) {
+            return Err((None, format!("the walls are {}x{} but the arena is {}x{}", walls.cols(), walls.rows(), width, height)));
+        }
+        match robots.iter().position(|r| self.is_wall(r.loc)) {
+            Some(i) => Err((Some(i), format!("robot {} starts inside a wall", i + 1))),
+            None => Ok(())
+        }
+    }
+
+    // One second for every robot, one tile at a time, wrapping at the edges
+    // as usual. Each robot moves along x first, then y.
+    pub(crate) fn elapse(&self, width: i16, height: i16, robots: &mut Vec<Robot>) {
+        for robot in robots.iter_mut() {
+            self.move_along(robot, 0, width);
+            self.move_along(robot, 1, height);
+        }
+        if self.merge {
+            let mut taken = HashSet::new();
+            robots.retain(|r| taken.insert(r.loc));
+        }
+    }
+
+    fn move_along(&self, robot: &mut Robot, axis: usize, size: i16) {
+        let get = |p: (i16, i16)| if axis == 0 {p.0} else {p.1};
+        let set = |p: &mut (i16, i16), v: i16| if axis == 0 {p.0 = v} else {p.1 = v};
+        let speed = get(robot.vel);
+        let mut dir = speed.signum();
+        for _ in 0..speed.abs() {
+            let mut next = robot.loc;
+            set(&mut next, (get(robot.loc) + dir).rem_euclid(size));
+            if self.is_wall(next) {
+                match self.walls.as_ref().map(|(_, on_wall)| *on_wall) {
+                    Some(OnWall::Stop) => {
+                        robot.vel = (0, 0);
+                        return;
+                    },
+                    _ => {
+                        dir = -dir;
+                        set(&mut robot.vel, dir * speed.abs());
+                        set(&mut next, (get(robot.loc) + dir).rem_euclid(size));
+                        if self.is_wall(next) {return;}
+                    }
+                }
+            }
+            robot.loc = next;
+        }
+    }
+}
+
+// A map of the arena with `#` for walls and `.` for open floor.
+pub fn read_walls(s: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::parse(s, "'#' or '.'", |_, c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Field, STEP_LIMIT};
+
+    // A 7x3 arena with a wall down column 4.
+    fn walled(on_wall: OnWall) -> Rules {
+        Rules::new().with_walls(read_walls("....#..\n....#..\n....#..\n").unwrap(), on_wall)
+    }
+
+    #[test]
+    fn bounce() {
+        let mut field = Field::new(7, 3, vec![Robot::new((1, 0), (2, 1))]).with_rules(walled(OnWall::Bounce)).unwrap();
+        field.elapse();
+        assert_eq!(field.snapshot(0), Some(vec![(3, 1)]));
+        field.elapse();
+        assert_eq!(field.snapshot(0), Some(vec![(1, 2)]));
+        assert_eq!(field.robots[0].vel, (-2, 1));
+        // Going left wraps round to column 6, then bounces off the other
+        // side of the wall.
+        assert_eq!(field.snapshot(1), Some(vec![(6, 0)]));
+        assert_eq!(field.snapshot(2), Some(vec![(6, 1)]));
+        assert_eq!(field.snapshot(3), Some(vec![(1, 2)]));
+        assert_eq!(field.snapshot(-1), None);
+    }
+
+    #[test]
+    fn stop() {
+        let field = Field::new(7, 3, vec![Robot::new((1, 0), (2, 1))]).with_rules(walled(OnWall::Stop)).unwrap();
+        assert_eq!(field.snapshot(1), Some(vec![(3, 1)]));
+        assert_eq!(field.snapshot(2), Some(vec![(3, 1)]));
+        assert_eq!(field.after(50).robots[0].vel, (0, 0));
+    }
+
+    #[test]
+    fn merge() {
+        let robots = vec![Robot::new((0, 0), (1, 0)), Robot::new((2, 0), (-1, 0)), Robot::new((5, 2), (0, 1))];
+        let field = Field::new(7, 3, robots).with_rules(Rules::new().with_merging()).unwrap();
+        assert_eq!(field.snapshot(1), Some(vec![(1, 0), (5, 0)]));
+        assert_eq!(field.snapshot(2), Some(vec![(2, 0), (5, 1)]));
+        assert_eq!(field.at(-3), None);
+        assert_eq!(field.at(STEP_LIMIT as i64 + 1), None);
+        assert!(field.at(STEP_LIMIT as i64).is_some());
+    }
+
+    #[test]
+    fn check() {
+        assert!(Field::new(7, 3, vec![Robot::new((4, 1), (0, 0))]).with_rules(walled(OnWall::Bounce)).is_err());
+        assert!(Field::new(8, 3, vec![]).with_rules(walled(OnWall::Bounce)).is_err());
+        assert!(read_walls("..#\n.x.\n").is_err());
+    }
+
+    #[test]
+    fn safety_factor_with_rules() {
+        let robots = (0..4).map(|i| Robot::new((i * 2 % 7, i % 3), (1, 1))).collect();
+        let plain = Field::new(7, 3, robots);
+        let open = plain.clone().with_rules(Rules::new().with_walls(Grid::new(3, 7, false), OnWall::Bounce)).unwrap();
+        for t in 0..30 {
+            assert_eq!(open.after(t).safety_factor(), plain.after(t).safety_factor());
+        }
+    }
+}
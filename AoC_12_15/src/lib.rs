@@ -0,0 +1,354 @@
+use std::collections::VecDeque;
+use aoc_common::{grid::Pos, render::{self, Frame, Tile}, Answer, Direction, Grid, ParseError, Solution};
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Map {
+    conts: Grid<MapCont>,
+    robot: Pos,
+    moves: VecDeque<Direction>
+}
+
+impl Map {
+    fn new(conts: Grid<MapCont>, robot: Pos, moves: VecDeque<Direction>) -> Self {
+        Self {
+            conts,
+            robot,
+            moves
+        }
+    }
+
+    fn widen(&self) -> Self {
+        let mut conts = Grid::new(self.conts.rows(), self.conts.cols() * 2, MapCont::Blank);
+        for ((i, j), cont) in self.conts.iter() {
+            let (left, right) = match cont {
+                MapCont::Wall => (MapCont::Wall, MapCont::Wall),
+                MapCont::Box => (MapCont::LeftBox, MapCont::RightBox),
+                MapCont::Robot => (MapCont::Robot, MapCont::Blank),
+                MapCont::Blank => (MapCont::Blank, MapCont::Blank),
+                x => {panic!("map already wide: {:?}", x);}
+            };
+            conts[(i, j*2)] = left;
+            conts[(i, j*2+1)] = right;
+        }
+        Map::new(conts, (self.robot.0, self.robot.1 * 2), self.moves.clone())
+    }
+
+    fn apply_moves(&mut self) {
+        while let Some(dir) = self.moves.pop_front() {
+            self.try_move(self.robot, dir);
+        }
+    }
+
+    fn try_move(&mut self, from: Pos, dir: Direction) -> bool {
+        use MapCont::*;
+        let dest = self.conts.step(from, dir).expect("move off the edge of the map");
+        let dest_cont = self.conts[dest];
+        let from_cont = self.conts[from];
+        match dest_cont {
+            Blank => {
+                self.conts[dest] = from_cont;
+                self.conts[from] = Blank;
+                if from_cont == Robot {self.robot = dest;}
+                true
+            },
+            Wall => {false},
+            Box => {
+                if self.try_move(dest, dir) {
+                    assert!(self.try_move(from, dir));
+                    true
+                } else {false}
+            },
+            Robot => {panic!("try_move call into robot loc");},
+            LeftBox => {
+                if dir.is_horizontal() {
+                    if self.try_move(dest, dir) {
+                        assert!(self.try_move(from, dir));
+                        true
+                    } else {false}
+                } else if self.can_move((dest.0, dest.1+1), dir) {
+                    if self.try_move(dest, dir) {
+                        assert!(self.try_move((dest.0, dest.1+1), dir));
+                        assert!(self.try_move(from, dir));
+                        true
+                    } else {false}
+                } else {false}
+            },
+            RightBox => {
+                if dir.is_horizontal() {
+                    if self.try_move(dest, dir) {
+                        assert!(self.try_move(from, dir));
+                        true
+                    } else {false}
+                } else if self.can_move((dest.0, dest.1-1), dir) {
+                    if self.try_move(dest, dir) {
+                        assert!(self.try_move((dest.0, dest.1-1), dir));
+                        assert!(self.try_move(from, dir));
+                        true
+                    } else {false}
+                } else {false}
+            }
+        }
+    }
+
+    fn can_move(&mut self, from: Pos, dir: Direction) -> bool {
+        use MapCont::*;
+        let dest = self.conts.step(from, dir).expect("move off the edge of the map");
+        match self.conts[dest] {
+            Blank => {true},
+            Wall => {false},
+            Box => {self.can_move(dest, dir)},
+            Robot => {panic!("try_move call into robot loc");},
+            LeftBox => {
+                if dir.is_horizontal() || self.can_move((dest.0, dest.1+1), dir) {
+                    self.can_move(dest, dir)
+                } else {false}
+            },
+            RightBox => {
+                if dir.is_horizontal() || self.can_move((dest.0, dest.1-1), dir) {
+                    self.can_move(dest, dir)
+                } else {false}
+            }
+        }
+    }
+
+    fn gps_sum(&self) -> usize {
+        let mut sum = 0;
+        for ((i, j), cont) in self.conts.iter() {
+            match *cont {
+                MapCont::Box => {sum += 100 * i + j;}
+                MapCont::LeftBox => {sum += 100 * i + j;},
+                _ => {}
+            }
+        }
+        sum
+    }
+
+    fn frame(&self) -> Frame {
+        use MapCont::*;
+        self.conts.map(|cont| match *cont {
+            Blank => Tile::new('.', render::DIM),
+            Robot => Tile::new('@', render::RED),
+            Wall => Tile::new('#', render::WALL),
+            Box => Tile::new('O', render::YELLOW),
+            LeftBox => Tile::new('[', render::YELLOW),
+            RightBox => Tile::new(']', render::YELLOW)
+        })
+    }
+}
+
+#[derive(PartialEq, Debug, Copy, Clone)]
+enum MapCont {
+    Wall, Box, Robot, Blank,
+    LeftBox, RightBox
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Map;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        read_to_map(s)
+    }
+
+    fn part1(map: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(map).into())
+    }
+
+    fn part2(map: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(map).into())
+    }
+
+    // The warehouse after every move, wide for part 2.
+    fn render(map: &Self::Input, part: u8) -> Option<Frame> {
+        let mut map = match part {
+            1 => map.clone(),
+            _ => map.widen()
+        };
+        map.apply_moves();
+        Some(map.frame())
+    }
+
+    // The warehouse before the first move and after each one.
+    fn animate(map: &Self::Input, part: u8, frame: &mut dyn FnMut(&Frame)) -> bool {
+        let mut map = match part {
+            1 => map.clone(),
+            _ => map.widen()
+        };
+        frame(&map.frame());
+        while let Some(dir) = map.moves.pop_front() {
+            map.try_move(map.robot, dir);
+            frame(&map.frame());
+        }
+        true
+    }
+}
+
+fn part1(map: &Map) -> usize {
+    let mut map = map.clone();
+    map.apply_moves();
+    map.gps_sum()
+}
+
+fn part2(map: &Map) -> usize {
+    let mut map = map.widen();
+    map.apply_moves();
+    map.gps_sum()
+}
+
+fn read_to_map(s: &str) -> Result<Map, ParseError> {
+    let mut map_len = 0;
+    for line in s.split_inclusive('\n') {
+        if line.trim().is_empty() {break;}
+        map_len += line.len();
+    }
+    let (map_text, moves_text) = s.split_at(map_len);
+    let mut robots: Vec<Pos> = vec![];
+    let conts = Grid::parse(map_text, "'#', 'O', '@' or '.'", |pos, c| match c {
+        '#' => Some(MapCont::Wall),
+        'O' => Some(MapCont::Box),
+        '@' => {
+            robots.push(pos);
+            Some(MapCont::Robot)
+        },
+        '.' => Some(MapCont::Blank),
+        _ => None
+    })?;
+    if let Some(&(i, j)) = robots.get(1) {
+        return Err(ParseError::invalid(i + 1, j + 1, "more than one robot"));
+    }
+    conts.walled(map_text, |&c| c == MapCont::Wall)?;
+    let height = map_text.lines().count();
+    if moves_text.trim().is_empty() {
+        return Err(ParseError::eof(height + 1, 1, "a blank line followed by moves"));
+    }
+
+    let mut moves = VecDeque::<Direction>::new();
+    for (i, line) in moves_text.lines().enumerate() {
+        for (j, c) in line.trim().chars().enumerate() {
+            let dir = Direction::from_arrow(c).ok_or_else(|| ParseError::unexpected(height + i + 1, j + 1, "one of \"^v<>\"", c))?;
+            moves.push_back(dir);
+        }
+    }
+
+    let robot_loc = *robots.first().ok_or_else(|| ParseError::invalid(height + 1, 1, "robot not found"))?;
+    Ok(Map::new(conts, robot_loc, moves))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read_to_map_() {
+        let map = read_to_map(include_str!("../../examples/day15/small.txt")).unwrap();
+        assert_eq!(map.conts.cols(), 8);
+        assert_eq!(map.conts.rows(), 8);
+        assert_eq!(map.robot, (2, 2));
+        assert_eq!(map.moves.iter().map(|d| d.arrow()).collect::<String>(), "<^^>>>vv<v>>v<<");
+        assert_eq!(map.conts.get((0, 5)), Some(&MapCont::Wall));
+        assert_eq!(map.conts.get((2, 2)), Some(&MapCont::Robot));
+        assert_eq!(map.conts.get((5, 4)), Some(&MapCont::Box));
+        assert_eq!(map.conts.get((5, 5)), Some(&MapCont::Blank));
+    }
+
+    #[test]
+    fn read_to_map_err() {
+        let e = read_to_map("####\n#@x#\n####\n\n<>\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.found()), (2, 3, Some('x')));
+        let e = read_to_map("####\n#@.#\n####\n\n<>\n^?\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.found()), (6, 2, Some('?')));
+        assert!(read_to_map("####\n#..#\n####\n\n<>\n").is_err());
+        let e = read_to_map("####\n#@.O\n####\n\n<>\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 4));
+    }
+
+    #[test]
+    fn gps_sum() {
+        let map = read_to_map("##########
+#.O.O.OOO#
+#........#
+#OO......#
+#OO@.....#
+#O#.....O#
+#O.....OO#
+#O.....OO#
+#OO....OO#
+##########
+
+>
+").unwrap();
+        assert_eq!(map.gps_sum(), 10092);
+    }
+
+    #[test]
+    fn apply_moves_() {
+        let mut map = read_to_map(include_str!("../../examples/day15/small.txt")).unwrap();
+        let expected = read_to_map("########
+#....OO#
+##.....#
+#.....O#
+#.#O@..#
+#...O..#
+#...O..#
+########
+
+>
+").unwrap();
+        map.apply_moves();
+        assert_eq!(map.conts, expected.conts);
+    }
+
+    #[test]
+    fn animate() {
+        let map = read_to_map(include_str!("../../examples/day15/small.txt")).unwrap();
+        let mut frames = vec![];
+        assert!(Day15::animate(&map, 2, &mut |f| frames.push(render::text(f))));
+        assert_eq!(frames.len(), 16);
+        assert_eq!(frames[0], render::text(&map.widen().frame()));
+        assert_eq!(frames.last(), Day15::render(&map, 2).map(|f| render::text(&f)).as_ref());
+    }
+
+    #[test]
+    fn widen() {
+        let map = read_to_map(include_str!("../../examples/day15/small.txt")).unwrap().widen();
+        assert_eq!(map.conts.cols(), 16);
+        assert_eq!(map.conts.rows(), 8);
+        assert_eq!(map.robot, (2, 4));
+        assert_eq!(map.moves.iter().map(|d| d.arrow()).collect::<String>(), "<^^>>>vv<v>>v<<");
+        assert_eq!(map.conts.get((0, 10)), Some(&MapCont::Wall));
+        assert_eq!(map.conts.get((0, 11)), Some(&MapCont::Wall));
+        assert_eq!(map.conts.get((2, 3)), Some(&MapCont::Wall));
+        assert_eq!(map.conts.get((2, 4)), Some(&MapCont::Robot));
+        assert_eq!(map.conts.get((2, 5)), Some(&MapCont::Blank));
+        assert_eq!(map.conts.get((5, 8)), Some(&MapCont::LeftBox));
+        assert_eq!(map.conts.get((5, 9)), Some(&MapCont::RightBox));
+        assert_eq!(map.conts.get((5, 10)), Some(&MapCont::Blank));
+        assert_eq!(map.conts.get((5, 11)), Some(&MapCont::Blank));
+    }
+
+    #[test]
+    fn apply_moves_wide() {
+        let mut map = read_to_map("#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^
+").unwrap().widen();
+        map.apply_moves();
+        assert_eq!(map.gps_sum(), 618);
+        assert_eq!(render::text(&map.frame()), "##############
+##...[].##..##
+##...@.[]...##
+##....[]....##
+##..........##
+##..........##
+##############
+");
+    }
+}
@@ -0,0 +1,388 @@
+use std::{cmp::min, collections::HashSet};
+use aoc_common::{grid::Pos, render::{self, Frame, Tile}, Answer, Direction, Grid, ParseError, Point, Solution};
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Maze {
+    map: Grid<Square>,
+    start: Pos,
+    end: Pos,
+    finishers: Vec<Reindeer>,
+    paths: HashSet<Pos>
+}
+
+impl Maze {
+    fn new(map: Grid<Square>, start: Pos, end: Pos) -> Self {
+        Self {
+            map,
+            start,
+            end,
+            finishers: vec![],
+            paths: HashSet::new()
+        }
+    }
+
+    fn solve(&mut self) {
+        let mut deer = vec![Reindeer::new(self.start)];
+        while !deer.is_empty() {
+            let mut new_deer = Vec::<Reindeer>::new();
+            while let Some(mut d) = deer.pop() {
+                let (result, v) = d.advance(self);
+                if result {
+                    new_deer.push(d);
+                    new_deer.extend(v);
+                }
+            }
+            deer = new_deer;
+        }
+        let best = self.score();
+        for finisher in self.finishers.iter() {
+            if Some(finisher.score) == best {
+                self.paths = self.paths.union(&finisher.path).copied().collect()
+            }
+        }
+    }
+
+    // None until a solve reaches the end.
+    fn score(&self) -> Option<u32> {
+        self.map[self.end].score()
+    }
+
+    fn update_square(&mut self, deer: &Reindeer) -> bool {
+        if deer.loc == self.end {
+            self.finishers.push(deer.clone());
+        }
+        let sq = &mut self.map[deer.loc];
+        match deer.dir {
+            Direction::Right => {
+                if let Some(prev) = sq.r {
+                    if deer.score <= prev {
+                        sq.r = Some(deer.score);
+                        true
+                    } else {false}
+                } else {
+                    sq.r = Some(deer.score);
+                    true
+                }
+            },
+            Direction::Down => {
+                if let Some(prev) = sq.d {
+                    if deer.score <= prev {
+                        sq.d = Some(deer.score);
+                        true
+                    } else {false}
+                } else {
+                    sq.d = Some(deer.score);
+                    true
+                }
+            },
+            Direction::Left => {
+                if let Some(prev) = sq.l {
+                    if deer.score <= prev {
+                        sq.l = Some(deer.score);
+                        true
+                    } else {false}
+                } else {
+                    sq.l = Some(deer.score);
+                    true
+                }
+            },
+            Direction::Up => {
+                if let Some(prev) = sq.u {
+                    if deer.score <= prev {
+                        sq.u = Some(deer.score);
+                        true
+                    } else {false}
+                } else {
+                    sq.u = Some(deer.score);
+                    true
+                }
+            }
+        }
+    }
+
+    // Walls, with the tiles on any best path marked.
+    fn frame(&self) -> Frame {
+        let mut frame = self.map.map(|sq| if sq.open {Tile::new('.', render::DIM)} else {Tile::new('#', render::WALL)});
+        for &pos in self.paths.iter() {
+            frame[pos] = Tile::new('O', render::GREEN);
+        }
+        frame[self.start] = Tile::new('S', render::BLUE);
+        frame[self.end] = Tile::new('E', render::RED);
+        frame
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+struct Reindeer {
+    loc: Pos,
+    dir: Direction,
+    score: u32,
+    path: HashSet<Pos>
+}
+
+impl Reindeer {
+    fn new(loc: Pos) -> Self {
+        Self {
+            loc,
+            dir: Direction::Right,
+            score: 0,
+            path: HashSet::from([loc])
+        }
+    }
+
+    fn advance(&mut self, maze: &mut Maze) -> (bool, Vec<Reindeer>) {
+        // println!("solving from ({}, {}, {})", self.loc.0, self.loc.1, self.dir);
+        if !maze.update_square(self) {return (false, vec![]);}
+        match (maze.map[self.in_front()].open, maze.map[self.to_left()].open, maze.map[self.to_right()].open) {
+            (false, false, false) => (false, vec![]),
+            (true, false, false) => {
+                self.move_forward();
+                (true, vec![])
+            },
+            (false, true, false) => {
+                self.turn_left();
+                self.move_forward();
+                (true, vec![])
+            },
+            (false, false, true) => {
+                self.turn_right();
+                self.move_forward();
+                (true, vec![])
+            },
+            (true, true, false) => {
+                let mut left_deer = self.clone();
+                self.move_forward();
+                left_deer.turn_left();
+                left_deer.move_forward();
+                (true, vec![left_deer])
+            },
+            (true, false, true) => {
+                let mut right_deer = self.clone();
+                self.move_forward();
+                right_deer.turn_right();
+                right_deer.move_forward();
+                (true, vec![right_deer])
+            },
+            (false, true, true) => {
+                let mut left_deer = self.clone();
+                self.turn_right();
+                self.move_forward();
+                left_deer.turn_left();
+                left_deer.move_forward();
+                (true, vec![left_deer])
+            },
+            (true, true, true) => {
+                let mut right_deer = self.clone();
+                let mut left_deer = self.clone();
+                self.move_forward();
+                right_deer.turn_right();
+                right_deer.move_forward();
+                left_deer.turn_left();
+                left_deer.move_forward();
+                (true, vec![right_deer, left_deer])
+            }
+        }
+    }
+
+    fn in_front(&self) -> Pos {
+        self.facing(self.dir)
+    }
+
+    fn to_left(&self) -> Pos {
+        self.facing(self.dir.turn_left())
+    }
+
+    fn to_right(&self) -> Pos {
+        self.facing(self.dir.turn_right())
+    }
+
+    fn facing(&self, dir: Direction) -> Pos {
+        Point::from(self.loc).step(dir).to_pos().expect("reindeer walked off the maze")
+    }
+
+    fn move_forward(&mut self) {
+        self.loc = self.in_front();
+        self.score += 1;
+        self.path.insert(self.loc);
+    }
+
+    fn turn_left(&mut self) {
+        self.dir = self.dir.turn_left();
+        self.score += 1000;
+    }
+
+    fn turn_right(&mut self) {
+        self.dir = self.dir.turn_right();
+        self.score += 1000;
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+struct Square {
+    open: bool,
+    l: Option<u32>,
+    r: Option<u32>,
+    u: Option<u32>,
+    d: Option<u32>
+}
+
+impl Square {
+    fn new(open: bool) -> Self {
+        Self {
+            open,
+            l: None,
+            r: None,
+            u: None,
+            d: None
+        }
+    }
+
+    fn score(&self) -> Option<u32> {
+        let mut score = Option::<u32>::None;
+        if let Some(ls) = self.l {
+            score = match score {
+                None => Some(ls),
+                Some(prev) => Some(min(prev, ls))
+            }
+        }
+        if let Some(rs) = self.r {
+            score = match score {
+                None => Some(rs),
+                Some(prev) => Some(min(prev, rs))
+            }
+        }
+        if let Some(us) = self.u {
+            score = match score {
+                None => Some(us),
+                Some(prev) => Some(min(prev, us))
+            }
+        }
+        if let Some(ds) = self.d {
+            score = match score {
+                None => Some(ds),
+                Some(prev) => Some(min(prev, ds))
+            }
+        }
+        score
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    type Input = Maze;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        read_maze(s)
+    }
+
+    fn part1(maze: &Self::Input) -> Result<Answer, String> {
+        part1(maze).map(Answer::from)
+    }
+
+    fn part2(maze: &Self::Input) -> Result<Answer, String> {
+        part2(maze).map(Answer::from)
+    }
+
+    fn render(maze: &Self::Input, _part: u8) -> Option<Frame> {
+        let mut maze = maze.clone();
+        maze.solve();
+        Some(maze.frame())
+    }
+}
+
+const NO_PATH: &str = "no path leads from S to E";
+
+fn part1(maze: &Maze) -> Result<u32, String> {
+    let mut maze = maze.clone();
+    maze.solve();
+    maze.score().ok_or(NO_PATH.to_string())
+}
+
+fn part2(maze: &Maze) -> Result<usize, String> {
+    let mut maze = maze.clone();
+    maze.solve();
+    maze.score().ok_or(NO_PATH.to_string())?;
+    Ok(maze.paths.len())
+}
+
+fn read_maze(s: &str) -> Result<Maze, ParseError> {
+    let mut starts: Vec<Pos> = vec![];
+    let mut ends: Vec<Pos> = vec![];
+    let map = Grid::parse(s, "'#', '.', 'S' or 'E'", |pos, c| match c {
+        '#' => Some(Square::new(false)),
+        '.' => Some(Square::new(true)),
+        'S' => {
+            starts.push(pos);
+            Some(Square::new(true))
+        },
+        'E' => {
+            ends.push(pos);
+            Some(Square::new(true))
+        },
+        _ => None
+    })?;
+    if let Some(&(i, j)) = starts.get(1) {return Err(ParseError::invalid(i + 1, j + 1, "more than one start"));}
+    if let Some(&(i, j)) = ends.get(1) {return Err(ParseError::invalid(i + 1, j + 1, "more than one end"));}
+    map.walled(s, |sq| !sq.open)?;
+    let start = *starts.first().ok_or_else(|| ParseError::invalid(map.rows() + 1, 1, "start not found"))?;
+    let end = *ends.first().ok_or_else(|| ParseError::invalid(map.rows() + 1, 1, "end not found"))?;
+    Ok(Maze::new(map, start, end))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read_maze_() {
+        let maze = read_maze(include_str!("../../examples/day16/small.txt")).unwrap();
+        assert!(!maze.map[(0, 0)].open);
+        assert!(maze.map[(1, 1)].open);
+        assert!(maze.map[(1, 13)].open);
+        assert!(maze.map[(13, 1)].open);
+        assert_eq!(maze.start, (13, 1));
+        assert_eq!(maze.end, (1, 13));
+        assert_eq!(maze.map.cols(), 15);
+        assert_eq!(maze.map.rows(), 15);
+    }
+
+    #[test]
+    fn read_maze_err() {
+        let e = read_maze("#####\n#S.E#\n#.o.#\n#####\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.found()), (3, 3, Some('o')));
+        let e = read_maze("#####\n#S..#\n#####\n").unwrap_err();
+        assert_eq!((e.line, e.column), (4, 1));
+        let e = read_maze("S.E\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+        let e = read_maze("####\n#SE#\n#.##\n").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 2));
+    }
+
+    #[test]
+    fn reindeer_turns() {
+        let mut deer = Reindeer::new((2, 2));
+        assert_eq!((deer.in_front(), deer.to_left(), deer.to_right()), ((2, 3), (1, 2), (3, 2)));
+        deer.turn_right();
+        deer.move_forward();
+        assert_eq!((deer.loc, deer.dir, deer.score), ((3, 2), Direction::Down, 1001));
+    }
+
+    #[test]
+    fn paths() {
+        let mut maze = read_maze(include_str!("../../examples/day16/small.txt")).unwrap();
+        maze.solve();
+        assert_eq!(maze.paths.len(), 45);
+        let text = render::text(&maze.frame());
+        assert_eq!(text.matches('O').count(), 43);
+        assert_eq!(text.lines().nth(13), Some("#S..#.....#OOO#"));
+    }
+
+    #[test]
+    fn unreachable_end() {
+        let maze = read_maze("#####\n#S#E#\n#####\n").unwrap();
+        assert_eq!(part1(&maze), Err(NO_PATH.to_string()));
+        assert_eq!(part2(&maze), Err(NO_PATH.to_string()));
+    }
+}
@@ -0,0 +1,213 @@
+use std::fmt::Write as _;
+use aoc_common::{ParseError, ParseErrorKind, Scanner};
+
+const MNEMONICS: [&str; 8] = ["adv", "bxl", "bst", "jnz", "bxc", "out", "bdv", "cdv"];
+
+// How an instruction reads its operand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operand {
+    Combo,
+    Literal,
+    Ignored
+}
+
+fn operand_kind(opcode: u8) -> Operand {
+    match opcode {
+        1 | 3 => Operand::Literal,
+        4 => Operand::Ignored,
+        _ => Operand::Combo
+    }
+}
+
+fn combo_name(operand: u8) -> String {
+    match operand {
+        4 => "A".to_string(),
+        5 => "B".to_string(),
+        6 => "C".to_string(),
+        n => n.to_string()
+    }
+}
+
+// One instruction, without its address.
+pub(crate) fn instruction(opcode: u8, operand: u8) -> String {
+    let mnemonic = MNEMONICS[opcode as usize];
+    match operand_kind(opcode) {
+        Operand::Combo => format!("{} {}", mnemonic, combo_name(operand)),
+        Operand::Literal => format!("{} {}", mnemonic, operand),
+        Operand::Ignored if operand == 0 => mnemonic.to_string(),
+        Operand::Ignored => format!("{} {}", mnemonic, operand)
+    }
+}
+
+// One instruction per line with its address, e.g. " 0: bst A". Jumps name the
+// instruction they land on. Pairs that are not instructions, including a
+// trailing lone opcode, come out as `data` lines so the listing assembles
+// back to the same program.
+pub fn disassemble(program: &[u8]) -> String {
+    let width = program.len().saturating_sub(1).to_string().len();
+    let mut listing = String::new();
+    for (ptr, pair) in program.chunks(2).enumerate().map(|(i, pair)| (i * 2, pair)) {
+        let _ = write!(listing, "{:>w$}: ", ptr, w = width);
+        match *pair {
+            [opcode, operand] if opcode < 8 && operand < 8 => {
+                listing.push_str(&instruction(opcode, operand));
+                if opcode == 3 {
+                    let target = operand as usize;
+                    let _ = match program.get(target..target + 2) {
+                        Some(&[op, arg]) if op < 8 && arg < 8 => write!(listing, "  ; -> {}", instruction(op, arg)),
+                        _ if target >= program.len() => write!(listing, "  ; -> end"),
+                        _ => write!(listing, "  ; -> data")
+                    };
+                }
+                if operand_kind(opcode) == Operand::Combo && operand == 7 {
+                    listing.push_str("  ; reserved operand");
+                }
+            },
+            [x, y] => {let _ = write!(listing, "data {}, {}", x, y);},
+            [x] => {let _ = write!(listing, "data {}", x);},
+            _ => {}
+        }
+        listing.push('\n');
+    }
+    listing
+}
+
+// Reads a listing like the one `disassemble` writes. Addresses and `;`
+// comments are optional; combo operands are 0 to 3, A, B, C or 7.
+pub fn assemble(s: &str) -> Result<Vec<u8>, ParseError> {
+    let mut sc = Scanner::new(s);
+    let mut program = vec![];
+    loop {
+        sc.skip_whitespace();
+        if sc.at_end() {break;}
+        if sc.peek() == Some(';') {
+            sc.skip_line();
+            continue;
+        }
+        let start = sc.pos();
+        if sc.num::<usize>().is_ok() && sc.char(':').is_ok() {
+            sc.skip_spaces();
+        } else {
+            sc.seek(start);
+        }
+
+        let word_start = sc.pos();
+        let word: String = sc.rest().chars().take_while(|c| c.is_ascii_alphabetic()).collect();
+        sc.seek(word_start + word.len());
+        if word == "data" {
+            sc.skip_spaces();
+            program.push(sc.num().map_err(|e| e.locate(s))?);
+            sc.skip_spaces();
+            while sc.char(',').is_ok() {
+                sc.skip_spaces();
+                program.push(sc.num().map_err(|e| e.locate(s))?);
+                sc.skip_spaces();
+            }
+        } else {
+            let Some(opcode) = MNEMONICS.iter().position(|&m| m == word) else {
+                let expected = "an instruction".to_string();
+                let kind = match sc.peek() {
+                    _ if !word.is_empty() => ParseErrorKind::Invalid { reason: format!("unknown instruction '{}'", word) },
+                    Some(found) => ParseErrorKind::Unexpected { expected, found },
+                    None => ParseErrorKind::Eof { expected }
+                };
+                return Err(ParseError::at(s, word_start, kind));
+            };
+            let opcode = opcode as u8;
+            let spaces = sc.skip_spaces();
+            let has_operand = spaces > 0 && sc.peek().is_some_and(|c| c != ';' && c != '\n' && c != '\r');
+            let operand = match (operand_kind(opcode), has_operand) {
+                (Operand::Ignored, false) => 0,
+                (Operand::Combo, true) => combo(&mut sc, s)?,
+                (_, true) => literal(&mut sc, s)?,
+                (_, false) => {return Err(ParseError::at(s, sc.pos(), ParseErrorKind::Eof { expected: "an operand".to_string() }));}
+            };
+            program.extend([opcode, operand]);
+        }
+
+        sc.skip_spaces();
+        if sc.peek() == Some(';') {sc.skip_line();}
+        else if !sc.at_end() {sc.line_ending().map_err(|e| e.locate(s))?;}
+    }
+    Ok(program)
+}
+
+fn combo(sc: &mut Scanner, s: &str) -> Result<u8, ParseError> {
+    let register = match sc.peek() {
+        Some('A') => 4,
+        Some('B') => 5,
+        Some('C') => 6,
+        _ => {
+            let start = sc.pos();
+            let n = literal(sc, s)?;
+            if (4..7).contains(&n) {
+                return Err(ParseError::at(s, start, ParseErrorKind::Invalid { reason: format!("combo operand {} reads register {}", n, combo_name(n)) }));
+            }
+            return Ok(n);
+        }
+    };
+    sc.next_char();
+    Ok(register)
+}
+
+fn literal(sc: &mut Scanner, s: &str) -> Result<u8, ParseError> {
+    let start = sc.pos();
+    let n: u8 = sc.num().map_err(|e| e.locate(s))?;
+    if n > 7 {
+        return Err(ParseError::at(s, start, ParseErrorKind::Invalid { reason: format!("operand {} is not 0 through 7", n) }));
+    }
+    Ok(n)
+}
+
+// The comma-separated form the puzzle input uses.
+pub fn program_str(program: &[u8]) -> String {
+    program.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(",")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const QUINE: [u8; 16] = [2, 4, 1, 2, 7, 5, 0, 3, 4, 7, 1, 7, 5, 5, 3, 0];
+
+    #[test]
+    fn disassemble_() {
+        assert_eq!(disassemble(&[0, 1, 5, 4, 3, 0]), "0: adv 1\n2: out A\n4: jnz 0  ; -> adv 1\n");
+        let listing = disassemble(&QUINE);
+        assert!(listing.starts_with(" 0: bst A\n 2: bxl 2\n 4: cdv B\n 6: adv 3\n 8: bxc 7\n"));
+        assert!(listing.ends_with("14: jnz 0  ; -> bst A\n"));
+        assert_eq!(disassemble(&[5, 7, 3, 8, 9, 1, 3, 6, 2]), "0: out 7  ; reserved operand\n2: data 3, 8\n4: data 9, 1\n6: jnz 6  ; -> jnz 6\n8: data 2\n");
+        assert_eq!(disassemble(&[3, 2]), "0: jnz 2  ; -> end\n");
+        assert_eq!(disassemble(&[3, 3, 1, 2]), "0: jnz 3  ; -> data\n2: bxl 2\n");
+    }
+
+    #[test]
+    fn round_trip() {
+        assert_eq!(assemble(&disassemble(&QUINE)).unwrap(), QUINE);
+        let odd = [5, 7, 3, 8, 9, 1, 3, 6, 2];
+        assert_eq!(assemble(&disassemble(&odd)).unwrap(), odd);
+    }
+
+    #[test]
+    fn assemble_() {
+        let s = "; count down\nadv 1\r\n  out A   ; print\n\nbxc\njnz 0\n";
+        assert_eq!(assemble(s).unwrap(), vec![0, 1, 5, 4, 4, 0, 3, 0]);
+        assert_eq!(program_str(&assemble(s).unwrap()), "0,1,5,4,4,0,3,0");
+    }
+
+    #[test]
+    fn assemble_err() {
+        let e = assemble("adv 1\nmul 2\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        let e = assemble("adv 4\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 5));
+        let e = assemble("bxl 8\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 5));
+        let e = assemble("out\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 4));
+        let e = assemble("out Bx\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.found()), (1, 6, Some('x')));
+        let e = assemble("0: !\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.found()), (1, 4, Some('!')));
+    }
+}
@@ -0,0 +1,94 @@
+use std::{env, io::{self, BufWriter, Write}, path::PathBuf, process::exit};
+use aoc_common::{runner::{default_source, read_source}, Solution};
+use aoc_12_17::{asm, decompile::Loop, trace::CSV_HEADER, Day17, Stop};
+
+// Every traced instruction is a line of output, so a program that never
+// halts is cut off well before it fills a disk.
+const TRACE_BUDGET: usize = 10_000;
+
+const USAGE: &str = "Usage: cargo run -p AoC_12_17 --bin asm -- [FILE | -]              print the listing of a puzzle input
+       cargo run -p AoC_12_17 --bin asm -- --decompile [FILE | -]  print what each pass of its loop computes
+       cargo run -p AoC_12_17 --bin asm -- --trace [--budget N] [FILE | -]
+                                                            run it and print each instruction as CSV,
+                                                            stopping after N instructions (default 10000)
+       cargo run -p AoC_12_17 --bin asm -- --assemble [FILE | -]   turn a listing into a program";
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    List,
+    Decompile,
+    Trace,
+    Assemble
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (mode, budget, source) = parse_args(&args).unwrap_or_else(|e| {
+        eprintln!("{}\n{}", e, USAGE);
+        exit(2);
+    });
+    let source = source.unwrap_or_else(|| default_source(Day17::DAY));
+    let input = read_source(&source).unwrap_or_else(|e| {
+        eprintln!("Error: could not read '{}': {}", PathBuf::from(&source).display(), e);
+        exit(1);
+    });
+
+    let result = match mode {
+        Mode::Assemble => asm::assemble(&input).map(|program| format!("Program: {}", asm::program_str(&program))).map_err(|e| e.to_string()),
+        Mode::List => Day17::parse(&input).map(|comp| asm::disassemble(comp.program()).trim_end().to_string()).map_err(|e| e.to_string()),
+        Mode::Decompile => Day17::parse(&input).map(|comp| match Loop::recognize(comp.program()) {
+            Some(body) => body.to_string(),
+            None => "The program is not a loop with a single `adv` by 1 to 3 and `jnz 0` at the end.".to_string()
+        }).map_err(|e| e.to_string()),
+        Mode::Trace => Day17::parse(&input).map_err(|e| e.to_string()).and_then(|mut comp| {
+            let mut out = BufWriter::new(io::stdout().lock());
+            let mut written = writeln!(out, "{}", CSV_HEADER);
+            let stop = comp.run(budget, &mut |step| {
+                if written.is_ok() {written = writeln!(out, "{}", step.csv_row());}
+            });
+            written.and_then(|_| out.flush()).map_err(|e| format!("could not write the trace: {}", e))?;
+            match stop {
+                Ok(Stop::Halted { steps }) => {eprintln!("Halted after {} instructions", steps);},
+                Ok(Stop::BudgetExceeded { steps }) => {eprintln!("Still running after {} instructions", steps);},
+                Err(fault) => {return Err(format!("program stopped: {}", fault));}
+            }
+            Ok(String::new())
+        })
+    };
+    match result {
+        Ok(out) if out.is_empty() => {},
+        Ok(out) => {println!("{}", out);},
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            exit(1);
+        }
+    }
+}
+
+fn parse_args(args: &[String]) -> Result<(Mode, usize, Option<String>), String> {
+    let mut mode = Mode::List;
+    let mut budget = None;
+    let mut source = None;
+    let mut arg_iter = args.iter();
+    while let Some(arg) = arg_iter.next() {
+        let next_mode = match arg.as_str() {
+            "--decompile" => Mode::Decompile,
+            "--trace" => Mode::Trace,
+            "--assemble" => Mode::Assemble,
+            "--budget" => {
+                let n = arg_iter.next().ok_or("--budget needs an instruction count")?;
+                budget = Some(n.parse::<usize>().map_err(|_| format!("expected an instruction count, found '{}'", n))?);
+                continue;
+            },
+            flag if flag.starts_with("--") => {return Err(format!("unknown option '{}'", flag));},
+            path => {
+                if source.replace(path.to_string()).is_some() {return Err("expected a single input".to_string());}
+                continue;
+            }
+        };
+        if mode != Mode::List {return Err("expected a single mode".to_string());}
+        mode = next_mode;
+    }
+    if budget.is_some() && mode != Mode::Trace {return Err("--budget goes with --trace".to_string());}
+    Ok((mode, budget.unwrap_or(TRACE_BUDGET), source))
+}
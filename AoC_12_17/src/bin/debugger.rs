@@ -0,0 +1,42 @@
+use std::{env, io::{self, BufRead, Write}, path::PathBuf, process::exit};
+use aoc_common::{runner::{default_input, read_input}, Solution};
+use aoc_12_17::{debugger::Debugger, Day17};
+
+const USAGE: &str = "Usage: cargo run -p AoC_12_17 --bin debugger -- [FILE]";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let path = match args.as_slice() {
+        // Standard input is for commands, so the input has to be a file.
+        [] => default_input(Day17::DAY).unwrap_or_else(|| {
+            eprintln!("Error: no AoC_12_17/input.txt here or above; pass a file\n{}", USAGE);
+            exit(1);
+        }),
+        [path] if path != "-" => PathBuf::from(path),
+        _ => {
+            eprintln!("{}", USAGE);
+            exit(2);
+        }
+    };
+    let input = read_input(&path).unwrap_or_else(|e| {
+        eprintln!("Error: could not read '{}': {}", path.display(), e);
+        exit(1);
+    });
+    let comp = Day17::parse(&input).unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        exit(1);
+    });
+
+    let mut dbg = Debugger::new(comp);
+    println!("{}\nType help for commands.", dbg.status());
+    let mut lines = io::stdin().lock().lines();
+    loop {
+        print!("(dbg) ");
+        let _ = io::stdout().flush();
+        let Some(Ok(line)) = lines.next() else {break;};
+        match dbg.command(&line) {
+            Some(reply) => {println!("{}", reply);},
+            None => {break;}
+        }
+    }
+}
@@ -0,0 +1,258 @@
+use std::fmt::Write as _;
+use crate::{asm, Computer, BUDGET};
+
+const HELP: &str = "\
+s, step [N]       run the next N instructions (default 1)
+c, continue       run to a breakpoint, a watched change or the end
+b, break PTR      stop when the pointer reaches PTR
+w, watch a|b|c    stop when the register changes
+d, delete PTR     remove a breakpoint
+u, unwatch a|b|c  remove a watchpoint
+set a|b|c N       change a register
+p, print          show the registers, pointer and output
+l, list           show the program
+i, info           show breakpoints and watchpoints
+reset             go back to the loaded state
+q, quit";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Register {
+    A, B, C
+}
+
+impl Register {
+    fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "a" | "A" => Ok(Register::A),
+            "b" | "B" => Ok(Register::B),
+            "c" | "C" => Ok(Register::C),
+            _ => Err(format!("expected register a, b or c, found '{}'", s))
+        }
+    }
+
+    fn name(self) -> char {
+        match self {
+            Register::A => 'A',
+            Register::B => 'B',
+            Register::C => 'C'
+        }
+    }
+}
+
+// Runs a `Computer` one instruction at a time under text commands.
+pub struct Debugger {
+    start: Computer,
+    comp: Computer,
+    steps: usize,
+    breakpoints: Vec<usize>,
+    watches: Vec<Register>
+}
+
+impl Debugger {
+    pub fn new(comp: Computer) -> Self {
+        Self {
+            start: comp.clone(),
+            comp,
+            steps: 0,
+            breakpoints: vec![],
+            watches: vec![]
+        }
+    }
+
+    // The reply to one command line, or None for quit.
+    pub fn command(&mut self, line: &str) -> Option<String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let reply = match words.as_slice() {
+            ["q" | "quit"] => {return None;},
+            [] | ["s" | "step"] => Ok(self.step(1)),
+            ["s" | "step", n] => number(n).map(|n| self.step(n)),
+            ["c" | "continue"] => Ok(self.run()),
+            ["b" | "break", ptr] => number(ptr).map(|ptr| self.set_breakpoint(ptr)),
+            ["d" | "delete", ptr] => number(ptr).and_then(|ptr| self.delete_breakpoint(ptr)),
+            ["w" | "watch", reg] => Register::parse(reg).map(|reg| self.watch(reg)),
+            ["u" | "unwatch", reg] => Register::parse(reg).and_then(|reg| self.unwatch(reg)),
+            ["set", reg, value] => Register::parse(reg).and_then(|reg| {
+                let value = number(value)?;
+                self.set_register(reg, value);
+                Ok(self.status())
+            }),
+            ["p" | "print"] => Ok(self.status()),
+            ["l" | "list"] => Ok(self.listing()),
+            ["i" | "info"] => Ok(self.info()),
+            ["reset"] => {
+                self.comp = self.start.clone();
+                self.steps = 0;
+                Ok(self.status())
+            },
+            ["h" | "help"] => Ok(HELP.to_string()),
+            _ => Err(format!("unknown command '{}', try help", line.trim()))
+        };
+        Some(reply.unwrap_or_else(|e| format!("error: {}", e)))
+    }
+
+    pub fn status(&self) -> String {
+        let mut status = format!("step {}  ptr {}  A={} B={} C={}  out: {}",
+            self.steps, self.comp.pointer, self.comp.reg_a, self.comp.reg_b, self.comp.reg_c, self.comp.output_str());
+        if self.halted() {status.push_str("  (halted)");}
+        status
+    }
+
+    fn halted(&self) -> bool {
+        self.comp.pointer >= self.comp.program.len()
+    }
+
+    fn register(&self, reg: Register) -> u64 {
+        match reg {
+            Register::A => self.comp.reg_a,
+            Register::B => self.comp.reg_b,
+            Register::C => self.comp.reg_c
+        }
+    }
+
+    fn set_register(&mut self, reg: Register, value: u64) {
+        match reg {
+            Register::A => {self.comp.reg_a = value;},
+            Register::B => {self.comp.reg_b = value;},
+            Register::C => {self.comp.reg_c = value;}
+        }
+    }
+
+    // Runs one instruction, returning why execution should stop there, if
+    // it should.
+    fn advance(&mut self) -> Option<String> {
+        let before: Vec<u64> = self.watches.iter().map(|&reg| self.register(reg)).collect();
+        match self.comp.execute() {
+            Ok(true) => {},
+            Ok(false) => {return Some("program halted".to_string());},
+            Err(fault) => {return Some(format!("program stopped: {}", fault));}
+        }
+        self.steps += 1;
+        let mut changes = vec![];
+        for (&reg, old) in self.watches.iter().zip(before) {
+            let new = self.register(reg);
+            if new != old {changes.push(format!("{} changed {} -> {}", reg.name(), old, new));}
+        }
+        if !changes.is_empty() {return Some(changes.join(", "));}
+        if self.breakpoints.contains(&self.comp.pointer) {
+            return Some(format!("breakpoint at {}", self.comp.pointer));
+        }
+        None
+    }
+
+    fn step(&mut self, n: usize) -> String {
+        let mut reply = String::new();
+        for _ in 0..n {
+            if self.halted() {
+                reply.push_str("program halted\n");
+                break;
+            }
+            let stop = self.advance();
+            let _ = writeln!(reply, "{}", self.status());
+            if let Some(reason) = stop {
+                let _ = writeln!(reply, "{}", reason);
+                break;
+            }
+        }
+        reply.trim_end().to_string()
+    }
+
+    fn run(&mut self) -> String {
+        if self.halted() {return format!("program halted\n{}", self.status());}
+        for _ in 0..BUDGET {
+            if let Some(reason) = self.advance() {
+                return format!("{}\n{}", reason, self.status());
+            }
+        }
+        format!("still running after {} instructions\n{}", BUDGET, self.status())
+    }
+
+    fn set_breakpoint(&mut self, ptr: usize) -> String {
+        if !self.breakpoints.contains(&ptr) {self.breakpoints.push(ptr);}
+        format!("breakpoint at {}", ptr)
+    }
+
+    fn delete_breakpoint(&mut self, ptr: usize) -> Result<String, String> {
+        let i = self.breakpoints.iter().position(|&b| b == ptr).ok_or_else(|| format!("no breakpoint at {}", ptr))?;
+        self.breakpoints.remove(i);
+        Ok(format!("deleted breakpoint at {}", ptr))
+    }
+
+    fn watch(&mut self, reg: Register) -> String {
+        if !self.watches.contains(&reg) {self.watches.push(reg);}
+        format!("watching {}", reg.name())
+    }
+
+    fn unwatch(&mut self, reg: Register) -> Result<String, String> {
+        let i = self.watches.iter().position(|&w| w == reg).ok_or_else(|| format!("not watching {}", reg.name()))?;
+        self.watches.remove(i);
+        Ok(format!("stopped watching {}", reg.name()))
+    }
+
+    // The disassembly, with `=>` at the pointer and `*` at breakpoints.
+    fn listing(&self) -> String {
+        let mut listing = String::new();
+        for (i, line) in asm::disassemble(&self.comp.program).lines().enumerate() {
+            let here = if i * 2 == self.comp.pointer {"=>"} else {"  "};
+            let mark = if self.breakpoints.contains(&(i * 2)) {'*'} else {' '};
+            let _ = writeln!(listing, "{}{} {}", here, mark, line);
+        }
+        listing.trim_end().to_string()
+    }
+
+    fn info(&self) -> String {
+        let breakpoints: Vec<String> = self.breakpoints.iter().map(|b| b.to_string()).collect();
+        let watches: Vec<String> = self.watches.iter().map(|w| w.name().to_string()).collect();
+        format!("breakpoints: {}\nwatching: {}", breakpoints.join(", "), watches.join(", "))
+    }
+}
+
+fn number<T: std::str::FromStr>(s: &str) -> Result<T, String> {
+    s.parse().map_err(|_| format!("expected a number, found '{}'", s))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn debugger() -> Debugger {
+        Debugger::new(Computer::new(729, 0, 0, vec![0, 1, 5, 4, 3, 0]))
+    }
+
+    #[test]
+    fn step() {
+        let mut dbg = debugger();
+        assert_eq!(dbg.command("s 2"), Some("step 1  ptr 2  A=364 B=0 C=0  out: \nstep 2  ptr 4  A=364 B=0 C=0  out: 4".to_string()));
+        assert_eq!(dbg.command("").unwrap(), "step 3  ptr 0  A=364 B=0 C=0  out: 4");
+        assert_eq!(dbg.command("reset").unwrap(), "step 0  ptr 0  A=729 B=0 C=0  out: ");
+        assert_eq!(dbg.command("q"), None);
+    }
+
+    #[test]
+    fn breakpoint() {
+        let mut dbg = debugger();
+        dbg.command("b 4");
+        assert_eq!(dbg.command("c").unwrap(), "breakpoint at 4\nstep 2  ptr 4  A=364 B=0 C=0  out: 4");
+        assert_eq!(dbg.command("c").unwrap(), "breakpoint at 4\nstep 5  ptr 4  A=182 B=0 C=0  out: 4,6");
+        dbg.command("d 4");
+        assert!(dbg.command("c").unwrap().ends_with("out: 4,6,3,5,6,3,5,2,1,0  (halted)"));
+        assert!(dbg.command("d 4").unwrap().starts_with("error"));
+    }
+
+    #[test]
+    fn watch() {
+        let mut dbg = debugger();
+        dbg.command("set b 5");
+        dbg.command("w a");
+        assert_eq!(dbg.command("c").unwrap(), "A changed 729 -> 364\nstep 1  ptr 2  A=364 B=5 C=0  out: ");
+        assert_eq!(dbg.command("info").unwrap(), "breakpoints: \nwatching: A");
+        assert!(dbg.command("w x").unwrap().starts_with("error"));
+    }
+
+    #[test]
+    fn listing() {
+        let mut dbg = debugger();
+        dbg.command("b 2");
+        dbg.command("s");
+        assert_eq!(dbg.listing(), "    0: adv 1\n=>* 2: out A\n    4: jnz 0  ; -> adv 1");
+    }
+}
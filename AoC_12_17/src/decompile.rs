@@ -0,0 +1,214 @@
+use std::fmt;
+
+// A value computed during one pass of the loop, in terms of the registers as
+// the pass starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expr {
+    Reg(char),
+    Const(u64),
+    Mod8(Box<Expr>),
+    Shr(Box<Expr>, Box<Expr>),
+    Xor(Vec<Expr>)
+}
+
+impl Expr {
+    fn mod8(self) -> Self {
+        match self {
+            Expr::Const(n) => Expr::Const(n % 8),
+            Expr::Mod8(x) => Expr::Mod8(x),
+            x => Expr::Mod8(Box::new(x))
+        }
+    }
+
+    fn shr(self, by: Expr) -> Self {
+        match by {
+            Expr::Const(0) => self,
+            by => Expr::Shr(Box::new(self), Box::new(by))
+        }
+    }
+
+    // Xors of xors become one list, so `B ^ 1 ^ C` needs no parentheses.
+    fn xor(self, other: Expr) -> Self {
+        let mut terms = match self {
+            Expr::Xor(terms) => terms,
+            x => vec![x]
+        };
+        match other {
+            Expr::Xor(more) => {terms.extend(more);},
+            x => {terms.push(x);}
+        }
+        Expr::Xor(terms)
+    }
+
+    // The largest value this can take, if it is bounded.
+    fn max(&self) -> Option<u64> {
+        match self {
+            Expr::Reg(_) => None,
+            Expr::Const(n) => Some(*n),
+            Expr::Mod8(_) => Some(7),
+            Expr::Shr(x, _) => x.max(),
+            Expr::Xor(terms) => terms.iter().try_fold(0, |m, t| Some(m.max(t.max()?)))
+                .map(|m| m.checked_add(1).map_or(u64::MAX, |m| m.next_power_of_two() - 1))
+        }
+    }
+
+    // How many of the low bits of A decide the low `width` bits of this
+    // value, or None if there is no bound.
+    fn a_bits(&self, width: u64) -> Option<u64> {
+        match self {
+            Expr::Reg('A') => Some(width),
+            Expr::Reg(_) | Expr::Const(_) => Some(0),
+            Expr::Mod8(x) => x.a_bits(width.min(3)),
+            Expr::Shr(x, by) => Some(x.a_bits(width.checked_add(by.max()?)?)?.max(by.a_bits(64)?)),
+            Expr::Xor(terms) => terms.iter().try_fold(0, |m, t| Some(m.max(t.a_bits(width)?)))
+        }
+    }
+
+    fn reads(&self, reg: char) -> bool {
+        match self {
+            Expr::Reg(r) => *r == reg,
+            Expr::Const(_) => false,
+            Expr::Mod8(x) => x.reads(reg),
+            Expr::Shr(x, by) => x.reads(reg) || by.reads(reg),
+            Expr::Xor(terms) => terms.iter().any(|t| t.reads(reg))
+        }
+    }
+
+    fn is_atom(&self) -> bool {
+        matches!(self, Expr::Reg(_) | Expr::Const(_))
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let wrap = |x: &Expr| if x.is_atom() {x.to_string()} else {format!("({})", x)};
+        match self {
+            Expr::Reg(r) => write!(f, "{}", r),
+            Expr::Const(n) => write!(f, "{}", n),
+            Expr::Mod8(x) => write!(f, "{} % 8", wrap(x)),
+            Expr::Shr(x, by) => write!(f, "{} >> {}", wrap(x), wrap(by)),
+            Expr::Xor(terms) => write!(f, "{}", terms.iter().map(wrap).collect::<Vec<_>>().join(" ^ "))
+        }
+    }
+}
+
+// A program of the usual shape: a body that shifts A right by a constant
+// once, ending in `jnz 0`, and prints values that depend only on A. B and C
+// carry over from one pass to the next, so a body that reads them before
+// setting them from A is not of this shape.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Loop {
+    pub shift: u32,
+    pub outputs: Vec<Expr>
+}
+
+impl Loop {
+    // None unless the program has that shape.
+    pub fn recognize(program: &[u8]) -> Option<Self> {
+        if !program.len().is_multiple_of(2) || program.iter().any(|&x| x > 7) {return None;}
+        let (body, end) = program.split_at(program.len().checked_sub(2)?);
+        if end != [3, 0] {return None;}
+
+        let (mut a, mut b, mut c) = (Expr::Reg('A'), Expr::Reg('B'), Expr::Reg('C'));
+        let mut shift = None;
+        let mut outputs = vec![];
+        for pair in body.chunks(2) {
+            let (opcode, operand) = (pair[0], pair[1]);
+            let combo = match operand {
+                0..=3 => Some(Expr::Const(operand as u64)),
+                4 => Some(a.clone()),
+                5 => Some(b.clone()),
+                6 => Some(c.clone()),
+                _ => None
+            };
+            if combo.is_none() && ![1, 3, 4].contains(&opcode) {return None;}
+            let combo = combo.unwrap_or(Expr::Const(0));
+            match opcode {
+                0 => {
+                    if shift.is_some() || !(1..=3).contains(&operand) {return None;}
+                    shift = Some(operand as u32);
+                    a = a.shr(combo);
+                },
+                1 => {b = b.xor(Expr::Const(operand as u64));},
+                2 => {b = combo.mod8();},
+                4 => {b = b.xor(c.clone());},
+                5 => {outputs.push(combo.mod8());},
+                6 => {b = a.clone().shr(combo);},
+                7 => {c = a.clone().shr(combo);},
+                _ => {return None;}
+            }
+        }
+        if outputs.is_empty() || outputs.iter().any(|out| out.reads('B') || out.reads('C')) {return None;}
+        Some(Self {
+            shift: shift?,
+            outputs
+        })
+    }
+
+    // How many low bits of A, as a pass starts, each output reads.
+    pub fn output_bits(&self) -> Vec<Option<u64>> {
+        self.outputs.iter().map(|out| out.a_bits(3)).collect()
+    }
+}
+
+impl fmt::Display for Loop {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "do {{")?;
+        for (out, bits) in self.outputs.iter().zip(self.output_bits()) {
+            match bits {
+                Some(bits) => writeln!(f, "    out = {}    // bits 0..{} of A", out, bits)?,
+                None => writeln!(f, "    out = {}", out)?
+            }
+        }
+        writeln!(f, "    A = A >> {}", self.shift)?;
+        write!(f, "}} while A != 0")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn recognize() {
+        let quine = Loop::recognize(&[2, 4, 1, 1, 7, 5, 4, 4, 1, 4, 0, 3, 5, 5, 3, 0]).unwrap();
+        assert_eq!(quine.shift, 3);
+        assert_eq!(quine.outputs.len(), 1);
+        assert_eq!(quine.outputs[0].to_string(), "((A % 8) ^ 1 ^ (A >> ((A % 8) ^ 1)) ^ 4) % 8");
+        assert_eq!(quine.output_bits(), vec![Some(10)]);
+        assert_eq!(quine.to_string(), "do {
+    out = ((A % 8) ^ 1 ^ (A >> ((A % 8) ^ 1)) ^ 4) % 8    // bits 0..10 of A
+    A = A >> 3
+} while A != 0");
+    }
+
+    #[test]
+    fn recognize_example() {
+        let example = Loop::recognize(&[0, 3, 5, 4, 3, 0]).unwrap();
+        assert_eq!(example.outputs, vec![Expr::Mod8(Box::new(Expr::Shr(Box::new(Expr::Reg('A')), Box::new(Expr::Const(3)))))]);
+        assert_eq!(example.output_bits(), vec![Some(6)]);
+    }
+
+    #[test]
+    fn recognize_none() {
+        assert_eq!(Loop::recognize(&[0, 1, 5, 4]), None);
+        assert_eq!(Loop::recognize(&[0, 4, 5, 4, 3, 0]), None);
+        assert_eq!(Loop::recognize(&[0, 3, 0, 3, 5, 4, 3, 0]), None);
+        assert_eq!(Loop::recognize(&[0, 3, 3, 0]), None);
+        assert_eq!(Loop::recognize(&[0, 3, 5, 4, 3, 2]), None);
+        assert_eq!(Loop::recognize(&[0, 3, 5, 7, 3, 0]), None);
+        assert!(Loop::recognize(&[0, 3, 2, 4, 7, 5, 4, 7, 1, 7, 5, 5, 3, 0]).is_some());
+        // Outputs that read B or C as the last pass left them.
+        assert_eq!(Loop::recognize(&[1, 1, 5, 5, 0, 3, 3, 0]), None);
+        assert_eq!(Loop::recognize(&[0, 3, 4, 7, 1, 7, 5, 5, 3, 0]), None);
+        assert_eq!(Loop::recognize(&[0, 3, 2, 6, 5, 5, 3, 0]), None);
+    }
+
+    #[test]
+    fn unbounded() {
+        // C = A >> B with B = A >> 3 reads as far up A as A goes.
+        let program = Loop::recognize(&[6, 3, 7, 5, 5, 6, 0, 3, 3, 0]).unwrap();
+        assert_eq!(program.outputs[0].to_string(), "(A >> (A >> 3)) % 8");
+        assert_eq!(program.output_bits(), vec![None]);
+    }
+}
@@ -0,0 +1,560 @@
+use std::fmt;
+use aoc_common::{Answer, ParseError, ParseErrorKind, Scanner, ScanError, Solution};
+
+pub mod asm;
+pub mod debugger;
+pub mod decompile;
+pub mod trace;
+
+use trace::Step;
+
+// Instructions a run may take before it is cut off. Puzzle programs need a
+// few hundred; this leaves room while still stopping a jump loop that never
+// ends.
+pub const BUDGET: usize = 100_000_000;
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct Computer {
+    reg_a: u64,
+    reg_b: u64,
+    reg_c: u64,
+    program: Vec<u8>,
+    pointer: usize,
+    output: Vec<u8>
+}
+
+impl Computer {
+    fn new(reg_a: u64, reg_b: u64, reg_c: u64, program: Vec<u8>) -> Self {
+        Self {
+            reg_a, reg_b, reg_c,
+            program,
+            pointer: 0,
+            output: vec![]
+        }
+    }
+
+    #[allow(dead_code)]
+    fn compose(reg_a: u64, reg_b: u64, reg_c: u64, program: Vec<u8>, pointer: usize, output: Vec<u8>) -> Self {
+        Self {
+            reg_a, reg_b, reg_c, program, pointer, output
+        }
+    }
+
+    pub fn program(&self) -> &[u8] {
+        &self.program
+    }
+
+    fn execute_all(&mut self) -> Result<Stop, Fault> {
+        self.run(BUDGET, &mut |_| {})
+    }
+
+    // Runs at most `budget` instructions, passing `trace` each one as it
+    // completes.
+    pub fn run(&mut self, budget: usize, trace: &mut dyn FnMut(&Step)) -> Result<Stop, Fault> {
+        for step in 0..budget {
+            let (pointer, printed) = (self.pointer, self.output.len());
+            if !self.execute()? {return Ok(Stop::Halted { steps: step });}
+            trace(&Step {
+                step: step + 1,
+                pointer,
+                opcode: self.program[pointer],
+                operand: self.program[pointer + 1],
+                reg_a: self.reg_a,
+                reg_b: self.reg_b,
+                reg_c: self.reg_c,
+                output: self.output.get(printed).copied()
+            });
+        }
+        match self.pointer >= self.program.len() {
+            true => Ok(Stop::Halted { steps: budget }),
+            false => Ok(Stop::BudgetExceeded { steps: budget })
+        }
+    }
+
+    // Ok(false) once the pointer has run off the end of the program.
+    fn execute(&mut self) -> Result<bool, Fault> {
+        let pointer = self.pointer;
+        let Some(&opcode) = self.program.get(pointer) else {return Ok(false);};
+        let Some(&operand) = self.program.get(pointer + 1) else {return Err(Fault::LoneOpcode { pointer });};
+        if opcode > 7 || operand > 7 {return Err(Fault::BadCode { pointer, code: opcode.max(operand) });}
+        if operand == 7 && matches!(opcode, 0 | 2 | 5 | 6 | 7) {return Err(Fault::ReservedOperand { pointer });}
+        match opcode {
+            0 => {
+                self.adv(operand);
+                Ok(true)
+            },
+            1 => {
+                self.bxl(operand);
+                Ok(true)
+            },
+            2 => {
+                self.bst(operand);
+                Ok(true)
+            },
+            3 => {
+                self.jnz(operand);
+                Ok(true)
+            },
+            4 => {
+                self.bxc();
+                Ok(true)
+            },
+            5 => {
+                self.out(operand);
+                Ok(true)
+            },
+            6 => {
+                self.bdv(operand);
+                Ok(true)
+            },
+            7 => {
+                self.cdv(operand);
+                Ok(true)
+            },
+            _ => {panic!("opcode {} was checked above", opcode);}
+        }
+    }
+
+    fn output_str(&self) -> String {
+        let mut output_iter = self.output.iter();
+        let mut op_str = match output_iter.next() {
+            None => {return "".to_string();},
+            Some(n) => n.to_string()
+        };
+        for n in output_iter {
+            op_str.extend([',']);
+            op_str.push_str(&n.to_string());
+        }
+        op_str
+    }
+
+    fn adv(&mut self, operand: u8) {
+        self.reg_a = self.divide(operand);
+        self.pointer += 2;
+    }
+
+    fn bxl(&mut self, operand: u8) {
+        assert!(operand < 8);
+        self.reg_b ^= operand as u64;
+        self.pointer += 2;
+    }
+
+    fn bst(&mut self, operand: u8) {
+        self.reg_b = self.combo(operand) % 8;
+        self.pointer += 2;
+    }
+
+    fn jnz(&mut self, operand: u8) {
+        assert!(operand < 8);
+        if self.reg_a == 0 {self.pointer += 2;}
+        else {self.pointer = operand as usize;}
+    }
+
+    fn bxc(&mut self) {
+        self.reg_b ^= self.reg_c;
+        self.pointer += 2;
+    }
+
+    fn out(&mut self, operand: u8) {
+        self.output.push((self.combo(operand) % 8) as u8);
+        self.pointer += 2;
+    }
+
+    fn bdv(&mut self, operand: u8) {
+        self.reg_b = self.divide(operand);
+        self.pointer += 2;
+    }
+
+    fn cdv(&mut self, operand: u8) {
+        self.reg_c = self.divide(operand);
+        self.pointer += 2;
+    }
+
+    // A divided by 2 to the power of the combo operand, as a shift so that
+    // big powers give 0 instead of overflowing.
+    fn divide(&self, operand: u8) -> u64 {
+        let shift = u32::try_from(self.combo(operand)).unwrap_or(u32::MAX);
+        self.reg_a.checked_shr(shift).unwrap_or(0)
+    }
+
+    fn combo(&self, operand: u8) -> u64 {
+        match operand {
+            0 => 0,
+            1 => 1,
+            2 => 2,
+            3 => 3,
+            4 => self.reg_a,
+            5 => self.reg_b,
+            6 => self.reg_c,
+            7 => {panic!("reserved combo operand 7");},
+            n => {panic!("expected operand 0 through 7, found {}", n);}
+        }
+    }
+}
+
+// How a run that did not fault ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+    Halted { steps: usize },
+    BudgetExceeded { steps: usize }
+}
+
+// Why a program stopped before running off its end.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fault {
+    LoneOpcode { pointer: usize },
+    BadCode { pointer: usize, code: u8 },
+    ReservedOperand { pointer: usize }
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Fault::LoneOpcode { pointer } => write!(f, "lone opcode at {}", pointer),
+            Fault::BadCode { pointer, code } => write!(f, "expected 0 through 7 at {}, found {}", pointer, code),
+            Fault::ReservedOperand { pointer } => write!(f, "reserved combo operand 7 at {}", pointer)
+        }
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    type Input = Computer;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        read_computer(s)
+    }
+
+    fn part1(comp: &Self::Input) -> Result<Answer, String> {
+        part1(comp).map(Answer::from)
+    }
+
+    fn part2(comp: &Self::Input) -> Result<Answer, String> {
+        part2(comp).map(Answer::from)
+    }
+}
+
+fn part1(comp: &Computer) -> Result<String, String> {
+    let mut comp = comp.clone();
+    match comp.execute_all() {
+        Ok(Stop::Halted { .. }) => Ok(comp.output_str()),
+        Ok(Stop::BudgetExceeded { steps }) => Err(format!("program still running after {} instructions", steps)),
+        Err(fault) => Err(format!("program stopped: {}", fault))
+    }
+}
+
+// Puzzle programs print a digit or a few per pass of their loop, then shift
+// A right by a few bits, so the last pass depends only on the top bits of A.
+// Working back from the end of the program, each pass fixes `shift` more
+// bits, and every candidate is checked by running the real program.
+fn part2(base: &Computer) -> Result<u64, String> {
+    let Some(body) = decompile::Loop::recognize(&base.program) else {
+        return Err("program is not a single out-and-shift loop printing values of A alone".to_string());
+    };
+    let shift = body.shift;
+    let per_pass = body.outputs.len();
+    let mut candidates = vec![0];
+    for pass in (0..base.program.len().div_ceil(per_pass)).rev() {
+        let start = pass * per_pass;
+        let mut next = vec![];
+        for a in candidates.iter().flat_map(|&a: &u64| (0..1 << shift).map(move |bits| a << shift | bits)) {
+            let mut variant = base.clone();
+            variant.reg_a = a;
+            if !matches!(variant.execute_all(), Ok(Stop::Halted { .. })) {continue;}
+            if variant.output == base.program[start..] {next.push(a);}
+        }
+        candidates = next;
+    }
+    candidates.into_iter().filter(|&a| a > 0).min().ok_or_else(|| "no value of register A makes the program print itself".to_string())
+}
+
+// Reads the "Register A: / Register B: / Register C: / Program:" format. The
+// spacing and blank lines between the parts may vary. Every opcode and
+// operand must be 0 through 7, and a program must not end on a lone opcode or
+// pass the reserved combo operand 7.
+fn read_computer(s: &str) -> Result<Computer, ParseError> {
+    let mut sc = Scanner::new(s);
+    let a = read_register(&mut sc, 'A').map_err(|e| e.locate(s))?;
+    let b = read_register(&mut sc, 'B').map_err(|e| e.locate(s))?;
+    let c = read_register(&mut sc, 'C').map_err(|e| e.locate(s))?;
+
+    sc.skip_whitespace();
+    sc.literal("Program:").map_err(|e| e.locate(s))?;
+    sc.skip_spaces();
+    let mut prog = Vec::<u8>::new();
+    loop {
+        let start = sc.pos();
+        let code: u8 = sc.num().map_err(|e| e.locate(s))?;
+        let reason = match prog.len() % 2 {
+            _ if code > 7 => Some(format!("expected 0 through 7, found {}", code)),
+            1 if code == 7 && matches!(prog.last(), Some(0 | 2 | 5 | 6 | 7)) => Some("combo operand 7 is reserved".to_string()),
+            _ => None
+        };
+        if let Some(reason) = reason {
+            return Err(ParseError::at(s, start, ParseErrorKind::Invalid { reason }));
+        }
+        prog.push(code);
+        sc.skip_spaces();
+        if sc.char(',').is_err() {break;}
+        sc.skip_spaces();
+    }
+    if prog.len() % 2 == 1 {
+        return Err(ParseError::at(s, sc.pos(), ParseErrorKind::Invalid { reason: "program ends on a lone opcode".to_string() }));
+    }
+    sc.skip_whitespace();
+    if let Some(found) = sc.peek() {
+        return Err(ParseError::at(s, sc.pos(), ParseErrorKind::Unexpected { expected: "the end of the input".to_string(), found }));
+    }
+
+    Ok(Computer::new(a, b, c, prog))
+}
+
+fn read_register(sc: &mut Scanner, name: char) -> Result<u64, ScanError> {
+    sc.skip_whitespace();
+    sc.literal("Register ")?;
+    sc.char(name)?;
+    sc.char(':')?;
+    sc.skip_spaces();
+    let value = sc.num()?;
+    sc.skip_spaces();
+    sc.line_ending()?;
+    Ok(value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read_computer_() {
+        let comp = read_computer(include_str!("../../examples/day17/output.txt")).unwrap();
+        assert_eq!(comp, Computer::compose(729, 0, 0, vec![0, 1, 5, 4, 3, 0], 0, vec![]));
+    }
+
+    #[test]
+    fn read_computer_err() {
+        let e = read_computer("Register A: 729\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,x,4").unwrap_err();
+        assert_eq!((e.line, e.column, e.found()), (5, 14, Some('x')));
+        let e = read_computer("Register A: 729\nRegister 8: 0\nRegister C: 0\n\nProgram: 0").unwrap_err();
+        assert_eq!((e.line, e.column, e.found()), (2, 10, Some('8')));
+        assert!(read_computer("Register A: 729\nRegister B: 0\nRegister C: 0\n").is_err());
+    }
+
+    #[test]
+    fn read_computer_c() {
+        let comp = read_computer("Register A: 0\r\nRegister B: 0\r\nRegister C:   9\r\n\r\nProgram: 2,6, 5,5\r\n").unwrap();
+        assert_eq!(comp, Computer::compose(0, 0, 9, vec![2, 6, 5, 5], 0, vec![]));
+        assert_eq!(part1(&comp), Ok("1".to_string()));
+        let comp = read_computer("Register A: 10\nRegister B: 29\nRegister C: 43690\nProgram: 4,0").unwrap();
+        assert_eq!((comp.reg_b, comp.reg_c), (29, 43690));
+    }
+
+    #[test]
+    fn part2_no_quine() {
+        // Each pass shifts A by one bit, so consecutive outputs share bits
+        // and 0 cannot be followed by 1.
+        let comp = read_computer(include_str!("../../examples/day17/output.txt")).unwrap();
+        assert_eq!(part2(&comp), Err("no value of register A makes the program print itself".to_string()));
+        let comp = read_computer(include_str!("../../examples/day17/register_c.txt")).unwrap();
+        assert_eq!(part2(&comp), Err("program is not a single out-and-shift loop printing values of A alone".to_string()));
+        let comp = read_computer("Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 1,1,5,5,0,3,3,0\n").unwrap();
+        assert!(part2(&comp).is_err());
+    }
+
+    #[test]
+    fn read_computer_invalid() {
+        let e = read_computer("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,8,4").unwrap_err();
+        assert_eq!((e.line, e.column), (5, 14));
+        let e = read_computer("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 1,7,5,7").unwrap_err();
+        assert_eq!((e.line, e.column), (5, 16));
+        let e = read_computer("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1,5").unwrap_err();
+        assert_eq!((e.line, e.column), (5, 15));
+        let e = read_computer("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1\nextra").unwrap_err();
+        assert_eq!((e.line, e.column, e.found()), (6, 1, Some('e')));
+        assert!(read_computer("Register A: 1 2\nRegister B: 0\nRegister C: 0\n\nProgram: 0,1").is_err());
+    }
+
+    #[test]
+    fn execute_faults() {
+        assert_eq!(Computer::new(1, 0, 0, vec![0, 1, 5]).execute_all(), Err(Fault::LoneOpcode { pointer: 2 }));
+        assert_eq!(Computer::new(1, 0, 0, vec![5, 7]).execute(), Err(Fault::ReservedOperand { pointer: 0 }));
+        assert_eq!(Computer::new(1, 0, 0, vec![9, 1]).execute(), Err(Fault::BadCode { pointer: 0, code: 9 }));
+        assert_eq!(Computer::new(1, 0, 0, vec![1, 7]).execute(), Ok(true));
+        assert_eq!(part1(&Computer::new(1, 0, 0, vec![0, 1, 5])), Err("program stopped: lone opcode at 2".to_string()));
+    }
+
+    #[test]
+    fn adv() {
+        let mut comp = Computer::new(100, 3, 1, vec![0, 5]);
+        comp.adv(5);
+        assert_eq!(comp, Computer::compose(12, 3, 1, vec![0, 5], 2, vec![]));
+    }
+
+    #[test]
+    fn adv_big_shift() {
+        let mut comp = Computer::new(1 << 40, 3, 70, vec![0, 6]);
+        comp.adv(6);
+        assert_eq!(comp.reg_a, 0);
+        let mut comp = Computer::new(1 << 40, 3, 37, vec![0, 6]);
+        comp.adv(6);
+        assert_eq!(comp.reg_a, 8);
+    }
+
+    #[test]
+    fn execute_adv() {
+        let mut comp = Computer::new(100, 3, 1, vec![0, 5]);
+        assert_eq!(comp.execute(), Ok(true));
+        assert_eq!(comp, Computer::compose(12, 3, 1, vec![0, 5], 2, vec![]));
+    }
+
+    #[test]
+    fn bxl() {
+        let mut comp = Computer::new(100, 21, 1, vec![1, 7]);
+        comp.bxl(7);
+        assert_eq!(comp, Computer::compose(100, 18, 1, vec![1, 7], 2, vec![]));
+    }
+
+    #[test]
+    fn execute_bxl() {
+        let mut comp = Computer::new(100, 21, 1, vec![1, 7]);
+        assert_eq!(comp.execute(), Ok(true));
+        assert_eq!(comp, Computer::compose(100, 18, 1, vec![1, 7], 2, vec![]));
+    }
+
+    #[test]
+    fn bst() {
+        let mut comp = Computer::new(100, 21, 1, vec![2, 6]);
+        comp.bst(6);
+        assert_eq!(comp, Computer::compose(100, 1, 1, vec![2, 6], 2, vec![]));
+    }
+
+    #[test]
+    fn execute_bst() {
+        let mut comp = Computer::new(100, 21, 1, vec![2, 6]);
+        assert_eq!(comp.execute(), Ok(true));
+        assert_eq!(comp, Computer::compose(100, 1, 1, vec![2, 6], 2, vec![]));
+    }
+
+    #[test]
+    fn jnz_a0() {
+        let mut comp = Computer::new(0, 21, 1, vec![3, 4]);
+        comp.jnz(4);
+        assert_eq!(comp, Computer::compose(0, 21, 1, vec![3, 4], 2, vec![]));
+    }
+
+    #[test]
+    fn jnz_jump() {
+        let mut comp = Computer::new(10, 21, 1, vec![3, 4]);
+        comp.jnz(4);
+        assert_eq!(comp, Computer::compose(10, 21, 1, vec![3, 4], 4, vec![]));
+    }
+
+    #[test]
+    fn execute_jnz() {
+        let mut comp = Computer::new(10, 21, 1, vec![3, 4]);
+        assert_eq!(comp.execute(), Ok(true));
+        assert_eq!(comp, Computer::compose(10, 21, 1, vec![3, 4], 4, vec![]));
+    }
+
+    #[test]
+    fn bxc() {
+        let mut comp = Computer::new(10, 100, 55, vec![4, 4]);
+        comp.bxc();
+        assert_eq!(comp, Computer::compose(10, 83, 55, vec![4, 4], 2, vec![]));
+    }
+
+    #[test]
+    fn xor_top_bit() {
+        let top = 1 << 63;
+        let mut comp = Computer::new(top, 0, 0, vec![6, 0, 4, 0, 5, 5]);
+        assert_eq!(comp.execute_all(), Ok(Stop::Halted { steps: 3 }));
+        assert_eq!((comp.reg_b, comp.output_str()), (top, "0".to_string()));
+        let mut comp = Computer::new(0, top | 5, top + 2, vec![1, 7]);
+        comp.bxl(7);
+        assert_eq!(comp.reg_b, top | 2);
+        comp.bxc();
+        assert_eq!(comp.reg_b, 0);
+    }
+
+    #[test]
+    fn execute_bxc() {
+        let mut comp = Computer::new(10, 100, 55, vec![4, 4]);
+        assert_eq!(comp.execute(), Ok(true));
+        assert_eq!(comp, Computer::compose(10, 83, 55, vec![4, 4], 2, vec![]));
+    }
+
+    #[test]
+    fn out() {
+        let mut comp = Computer::new(10, 100, 55, vec![5, 5]);
+        comp.out(5);
+        assert_eq!(comp, Computer::compose(10, 100, 55, vec![5, 5], 2, vec![4]));
+    }
+
+    #[test]
+    fn execute_out() {
+        let mut comp = Computer::new(10, 100, 55, vec![5, 5]);
+        assert_eq!(comp.execute(), Ok(true));
+        assert_eq!(comp, Computer::compose(10, 100, 55, vec![5, 5], 2, vec![4]));
+    }
+
+    #[test]
+    fn bdv() {
+        let mut comp = Computer::new(10, 100, 55, vec![6, 2]);
+        comp.bdv(2);
+        assert_eq!(comp, Computer::compose(10, 2, 55, vec![6, 2], 2, vec![]));
+    }
+
+    #[test]
+    fn execute_bdv() {
+        let mut comp = Computer::new(10, 100, 55, vec![6, 2]);
+        assert_eq!(comp.execute(), Ok(true));
+        assert_eq!(comp, Computer::compose(10, 2, 55, vec![6, 2], 2, vec![]));
+    }
+
+    #[test]
+    fn cdv() {
+        let mut comp = Computer::new(123, 100, 3, vec![7, 6]);
+        comp.cdv(6);
+        assert_eq!(comp, Computer::compose(123, 100, 15, vec![7, 6], 2, vec![]));
+    }
+
+    #[test]
+    fn execute_cdv() {
+        let mut comp = Computer::new(123, 100, 3, vec![7, 6]);
+        assert_eq!(comp.execute(), Ok(true));
+        assert_eq!(comp, Computer::compose(123, 100, 15, vec![7, 6], 2, vec![]));
+    }
+
+    #[test]
+    fn execute_all() {
+        let mut comp = Computer::compose(729, 0, 0, vec![0, 1, 5, 4, 3, 0], 0, vec![]);
+        assert_eq!(comp.execute_all(), Ok(Stop::Halted { steps: 30 }));
+        assert_eq!(comp, Computer::compose(0, 0, 0, vec![0, 1, 5, 4, 3, 0], 6, vec![4, 6, 3, 5, 6, 3, 5, 2, 1, 0]));
+    }
+
+    #[test]
+    fn run_budget() {
+        let mut comp = Computer::new(1, 0, 0, vec![1, 1, 3, 0]);
+        assert_eq!(comp.run(5, &mut |_| {}), Ok(Stop::BudgetExceeded { steps: 5 }));
+        let mut comp = Computer::new(729, 0, 0, vec![0, 1, 5, 4, 3, 0]);
+        assert_eq!(comp.run(30, &mut |_| {}), Ok(Stop::Halted { steps: 30 }));
+    }
+
+    #[test]
+    fn run_trace() {
+        let mut comp = Computer::new(729, 0, 0, vec![0, 1, 5, 4, 3, 0]);
+        let mut steps = vec![];
+        comp.run(BUDGET, &mut |step| steps.push(step.clone())).unwrap();
+        assert_eq!(steps.len(), 30);
+        assert_eq!(steps[1], Step { step: 2, pointer: 2, opcode: 5, operand: 4, reg_a: 364, reg_b: 0, reg_c: 0, output: Some(4) });
+        assert_eq!(steps[29].output, None);
+    }
+
+    #[test]
+    fn output_str() {
+        let comp = Computer::compose(0, 0, 0, vec![0, 1, 5, 4, 3, 0], 6, vec![4, 6, 3, 5, 6, 3, 5, 2, 1, 0]);
+        assert_eq!(comp.output_str(), "4,6,3,5,6,3,5,2,1,0");
+    }
+}
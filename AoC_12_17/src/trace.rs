@@ -0,0 +1,65 @@
+use std::fmt::Write as _;
+use crate::asm;
+
+pub const CSV_HEADER: &str = "step,pointer,instruction,a,b,c,out";
+
+// One executed instruction and the registers after it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub step: usize,
+    pub pointer: usize,
+    pub opcode: u8,
+    pub operand: u8,
+    pub reg_a: u64,
+    pub reg_b: u64,
+    pub reg_c: u64,
+    pub output: Option<u8>
+}
+
+impl Step {
+    pub fn csv_row(&self) -> String {
+        let out = self.output.map_or(String::new(), |x| x.to_string());
+        format!("{},{},{},{},{},{},{}",
+            self.step, self.pointer, asm::instruction(self.opcode, self.operand), self.reg_a, self.reg_b, self.reg_c, out)
+    }
+}
+
+// Collects the steps of a run, one CSV row each, so two runs can be diffed
+// or loaded into a spreadsheet. Long runs are better written out a row at a
+// time with `CSV_HEADER` and `Step::csv_row`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trace {
+    pub steps: Vec<Step>
+}
+
+impl Trace {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, step: &Step) {
+        self.steps.push(step.clone());
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("{}\n", CSV_HEADER);
+        for step in self.steps.iter() {
+            let _ = writeln!(csv, "{}", step.csv_row());
+        }
+        csv
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Computer;
+
+    #[test]
+    fn to_csv() {
+        let mut comp = Computer::new(10, 0, 0, vec![5, 4, 1, 3]);
+        let mut trace = Trace::new();
+        comp.run(10, &mut |step| trace.record(step)).unwrap();
+        assert_eq!(trace.to_csv(), "step,pointer,instruction,a,b,c,out\n1,0,out A,10,0,0,2\n2,2,bxl 3,10,3,0,\n");
+    }
+}
@@ -0,0 +1,153 @@
+use aoc_common::{Answer, ParseError, Scanner, ScanError, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        read_reports(s).map_err(|e| e.locate(s))
+    }
+
+    fn part1(reports: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(reports).into())
+    }
+
+    fn part2(reports: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(reports).into())
+    }
+}
+
+fn part1(reports: &[Vec<i32>]) -> i32 {
+    let mut num_safe = 0;
+    for report in reports.iter() {
+        num_safe += is_safe(report) as i32;
+    }
+    num_safe
+}
+
+fn part2(reports: &[Vec<i32>]) -> i32 {
+    let mut num_safe = 0;
+    for report in reports.iter() {
+        if is_safe(report) {
+            num_safe += 1;
+        } else {
+            for removal in every_removal(report).iter() {
+                if is_safe(removal) {
+                    num_safe += 1;
+                    break;
+                }
+            }
+        }
+    }
+    num_safe
+}
+
+fn read_reports(s: &str) -> Result<Vec<Vec<i32>>, ScanError> {
+    let mut reports: Vec<Vec<i32>> = Vec::new();
+    let mut sc = Scanner::new(s);
+    while !sc.at_end() {
+        let report = read_report(&mut sc)?;
+        if !report.is_empty() {
+            reports.push(report);
+        }
+    }
+    Ok(reports)
+}
+
+fn read_report(sc: &mut Scanner) -> Result<Vec<i32>, ScanError> {
+    let mut levels: Vec<i32> = Vec::new();
+    sc.skip_spaces();
+    while sc.peek().is_some_and(|c| c.is_ascii_digit()) {
+        levels.push(sc.num()?);
+        sc.skip_spaces();
+    }
+    if !sc.at_end() {sc.line_ending()?;}
+    Ok(levels)
+}
+
+fn is_safe(l: &[i32]) -> bool {
+    let mut greater = false;
+    let mut less = false;
+    for i in 1..l.len() {
+        if l[i] > l[i-1] && l[i] - l[i-1] <= 3 && !less {
+            greater = true;
+        } else if l[i] < l[i-1] && l[i-1] - l[i] <= 3 && !greater {
+            less = true;
+        } else {return false;}
+    }
+    true
+}
+
+fn every_removal(l: &[i32]) -> Vec<Vec<i32>> {
+    let len_l = l.len();
+    if len_l < 2 {panic!("report with less than 2 levels");}
+    let mut removals: Vec<Vec<i32>> = Vec::new();
+    removals.push(l[1..].to_vec());
+    for i in 1..len_l-1 {
+        removals.push([&l[..i], &l[i+1..]].concat());
+    }
+    removals.push(l[..len_l-1].to_vec());
+    removals
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn read_report_() {
+        let s = " 7 6 4 2 1\n1 2 7 8 9\n9 7 6 2 1";
+        assert_eq!(read_report(&mut Scanner::new(s)).unwrap(), vec![7, 6, 4, 2, 1]);
+    }
+
+    #[test]
+    fn read_reports_err() {
+        let s = "1 2\n3 -4\n";
+        let e = read_reports(s).unwrap_err().locate(s);
+        assert_eq!((e.line, e.column, e.found()), (2, 3, Some('-')));
+    }
+
+    #[test]
+    fn is_safe_1() {
+        let s = " 7 6 4 2 1";
+        assert!(is_safe(&read_report(&mut Scanner::new(s)).unwrap()));
+    }
+
+    #[test]
+    fn is_safe_2() {
+        let s = "1 2 7 8 9";
+        assert!(!is_safe(&read_report(&mut Scanner::new(s)).unwrap()));
+    }
+
+    #[test]
+    fn is_safe_3() {
+        let s = "9 7 6 2 1";
+        assert!(!is_safe(&read_report(&mut Scanner::new(s)).unwrap()));
+    }
+
+    #[test]
+    fn is_safe_4() {
+        let s = "1 3 2 4 5";
+        assert!(!is_safe(&read_report(&mut Scanner::new(s)).unwrap()));
+    }
+
+    #[test]
+    fn is_safe_5() {
+        let s = "8 6 4 4 1";
+        assert!(!is_safe(&read_report(&mut Scanner::new(s)).unwrap()));
+    }
+
+    #[test]
+    fn is_safe_6() {
+        let s = "1 3 6 7 9";
+        assert!(is_safe(&read_report(&mut Scanner::new(s)).unwrap()));
+    }
+
+    #[test]
+    fn every_removal_() {
+        let v = vec![1, 2, 3, 4];
+        assert_eq!(every_removal(&v), vec![vec![2, 3, 4], vec![1, 3, 4], vec![1, 2, 4], vec![1, 2, 3]]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,102 @@
+use aoc_common::{Answer, ParseError, Scanner, ScanError, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = String;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        Ok(s.to_string())
+    }
+
+    fn part1(memory: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(memory).into())
+    }
+
+    fn part2(memory: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(memory).into())
+    }
+}
+
+fn part1(s: &str) -> i32 {
+    let mut total = 0;
+    let mut sc = Scanner::new(s);
+    while let Some(c) = sc.next_char() {
+        if c == 'm' {
+            if let Ok(x) = scan_mul(&mut sc) {
+                total += x;
+            }
+        }
+    }
+    total
+}
+
+fn part2(s: &str) -> i32 {
+    let mut total = 0;
+    let mut sc = Scanner::new(s);
+    let mut enable = true;
+    while let Some(c) = sc.next_char() {
+        match c {
+            'm' => {
+                if !enable {continue;}
+                if let Ok(x) = scan_mul(&mut sc) {
+                    total += x;
+                }
+            },
+            'd' => {
+                let _ = scan_switch(&mut sc, &mut enable);
+            }
+            _ => {}
+        }
+    }
+    total
+}
+
+fn scan_mul(sc: &mut Scanner) -> Result<i32, ScanError> {
+    sc.literal("ul(")?;
+    let mut total: i32 = sc.num()?;
+    sc.char(',')?;
+    total *= sc.num::<i32>()?;
+    sc.char(')')?;
+    Ok(total)
+}
+
+fn scan_switch(sc: &mut Scanner, enable: &mut bool) -> Result<(), ScanError> {
+    if sc.literal("on't()").is_ok() {
+        *enable = false;
+    } else {
+        sc.literal("o()")?;
+        *enable = true;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn part1_() {
+        let s = "$  mul(402,190))&<why(211,617)how()/;mul(506,313)[^^<!$#when(636,198),]mul(744,268)#&!wmul(2";
+        assert_eq!(part1(s), 402*190+506*313+744*268);
+    }
+
+    #[test]
+    fn part2_() {
+        let s = "$  mul(402,190))&<why(211,617)how()/;mul(506,313)[^^<!$#when(636,198),]mul(744,268)#&!wmul(2";
+        assert_eq!(part2(s), 402*190+506*313+744*268);
+    }
+
+    #[test]
+    fn scan_disable() {
+        let s = "$893hdiodon't()4%^mul(402,190))&<why(211,617)how()/;mul(506,313)[^^<!$#when(636,198),]mul(744,268)#&!wmul(2";
+        assert_eq!(part2(s), 0);
+    }
+
+    #[test]
+    fn scan_mixed() {
+        let s = "$  mul(402,190))&<why(211,617)how()/;mul(506,313)[^^<!$#when(636,198),]mul(744,268)#&!what()&!;ul(206,770){/}don't()from()mul(260,967)-how() -/[^(mul(500,994)!:mul(391,833)#)>who(),where(376,378)why();$mul(394,346)%:]^from()>:mul(130,944)who()>where()select()}:mul(952,439)?/how()from()^$why()do()@*{mul(557,916)&(w";
+        assert_eq!(part2(s), 402*190+506*313+744*268+557*916);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,108 @@
+use aoc_common::{grid::{Pos, NEIGHBORS8}, Answer, Grid, ParseError, Solution};
+
+pub type WordSearch = Grid<char>;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = WordSearch;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        scan_to_map(s)
+    }
+
+    fn part1(scan: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(scan).into())
+    }
+
+    fn part2(scan: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(scan).into())
+    }
+}
+
+fn part1(scan: &WordSearch) -> i32 {
+    let mut num = 0;
+    for pos in scan.positions() {
+        num += scan_everywhere(scan, pos);
+    }
+    num
+}
+
+fn part2(scan: &WordSearch) -> i32 {
+    let mut num = 0;
+    for (pos, &c) in scan.iter() {
+        if c == 'A' {num += check_a(scan, pos) as i32;}
+    }
+    num
+}
+
+fn scan_to_map(s: &str) -> Result<WordSearch, ParseError> {
+    Grid::parse(s, "one of \"XMAS.\"", |_, c| match c {
+        'X' | 'M' | 'A' | 'S' | '.' => Some(c),
+        _ => None
+    })
+}
+
+fn scan_everywhere(map: &WordSearch, pos: Pos) -> i32 {
+    let mut found = 0;
+    for step in NEIGHBORS8 {
+        found += scan(map, pos, step) as i32;
+    }
+    found
+}
+
+fn scan(map: &WordSearch, pos: Pos, step: (isize, isize)) -> bool {
+    let mut pos = Some(pos);
+    for c in "XMAS".chars() {
+        match pos {
+            Some(p) if map[p] == c => {pos = map.offset(p, step);},
+            _ => {return false;}
+        }
+    }
+    true
+}
+
+fn check_a(map: &WordSearch, pos: Pos) -> bool {
+    let corner = |step| map.offset(pos, step).map(|p| map[p]);
+    if let (Some(ul), Some(ur), Some(dr), Some(dl)) = (corner((-1, -1)), corner((-1, 1)), corner((1, 1)), corner((1, -1))) {
+        ((ul == 'M' && dr == 'S') || (ul == 'S' && dr == 'M')) && ((ur == 'M' && dl == 'S') || (ur == 'S' && dl == 'M'))
+    } else {false}
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn scan_to_map_() {
+        let s = "MMM\nXMS\nAXM\n";
+        let map = scan_to_map(s).unwrap();
+        assert_eq!((map.rows(), map.cols()), (3, 3));
+        assert_eq!(map.iter().map(|(_, &c)| c).collect::<String>(), "MMMXMSAXM");
+    }
+
+    #[test]
+    fn scan_to_map_err() {
+        let e = scan_to_map("XMAS\nXMAZ\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.found()), (2, 4, Some('Z')));
+    }
+
+    #[test]
+    fn check_a_() {
+        let s = include_str!("../../examples/day04/xmas.txt");
+        let map = scan_to_map(s).unwrap();
+        assert!(check_a(&map, (1, 2)));
+        assert!(!check_a(&map, (1, 9)));
+        assert!(!check_a(&map, (0, 4)));
+    }
+
+    #[test]
+    fn scan_() {
+        let map = scan_to_map("XMAS\n.M..\n..A.\n...S\n").unwrap();
+        assert!(scan(&map, (0, 0), (0, 1)));
+        assert!(scan(&map, (0, 0), (1, 1)));
+        assert!(!scan(&map, (0, 0), (1, 0)));
+        assert!(!scan(&map, (0, 3), (0, 1)));
+    }
+}
\ No newline at end of file
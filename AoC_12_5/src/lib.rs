@@ -0,0 +1,216 @@
+use aoc_common::{Answer, ParseError, Scanner, ScanError, Solution};
+
+pub type Manual = (Vec<(i32, i32)>, Vec<Vec<i32>>);
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = Manual;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        scan_manual(s).map_err(|e| e.locate(s))
+    }
+
+    fn part1(manual: &Self::Input) -> Result<Answer, String> {
+        Ok(sum_of_middles(manual).into())
+    }
+
+    fn part2(manual: &Self::Input) -> Result<Answer, String> {
+        Ok(sum_of_corrected(manual).into())
+    }
+}
+
+fn sum_of_middles(manual: &Manual) -> i32 {
+    let (rules, reports) = manual;
+    let mut total = 0;
+    for report in reports.iter() {
+        total += middle_if_valid(report, rules);
+    }
+    total
+}
+
+fn sum_of_corrected(manual: &Manual) -> i32 {
+    let (rules, reports) = manual;
+    let mut total = 0;
+    for report in reports.iter() {
+        if !passes_all_rules(report, rules) {
+            let relevant = relevant_rules(report, rules);
+            let corrected = sequence_from_rules(&relevant, report);
+            total += middle_of_report(&corrected);
+        }
+    }
+    total
+}
+
+fn scan_manual(s: &str) -> Result<Manual, ScanError> {
+    let mut sc = Scanner::new(s);
+    let rules = scan_rules(&mut sc)?;
+    let reports = scan_reports(&mut sc)?;
+    Ok((rules, reports))
+}
+
+fn scan_rules(sc: &mut Scanner) -> Result<Vec<(i32, i32)>, ScanError> {
+    let mut rules: Vec<(i32, i32)> = Vec::new();
+    loop {
+        let x = sc.num()?;
+        sc.char('|')?;
+        let y = sc.num()?;
+        rules.push((x, y));
+        sc.line_ending()?;
+        if sc.line_ending().is_ok() {
+            return Ok(rules);
+        }
+    }
+}
+
+fn scan_reports(sc: &mut Scanner) -> Result<Vec<Vec<i32>>, ScanError> {
+    let mut reports: Vec<Vec<i32>> = Vec::new();
+    sc.skip_whitespace();
+    while !sc.at_end() {
+        let mut report: Vec<i32> = vec![sc.num()?];
+        while sc.char(',').is_ok() {
+            report.push(sc.num()?);
+        }
+        reports.push(report);
+        sc.skip_whitespace();
+    }
+    Ok(reports)
+}
+
+fn middle_if_valid(report: &[i32], rules: &[(i32, i32)]) -> i32 {
+    if passes_all_rules(report, rules) {
+        middle_of_report(report)
+    } else {0}
+}
+
+fn passes_all_rules(report: &[i32], rules: &[(i32, i32)]) -> bool {
+    for rule in rules.iter() {
+        if !passes_rule(report, rule) {
+            return false;
+        }
+    }
+    true
+}
+
+fn passes_rule(report: &[i32], rule: &(i32, i32)) -> bool {
+    let (x, y) = *rule;
+    if let Some(i1) = report.iter().position(|&rule| rule == x) {
+        if let Some(i2) = report.iter().position(|&rule| rule == y) {
+            if i2 < i1 {return false;}
+        }
+    }
+    true
+}
+
+fn middle_of_report(report: &[i32]) -> i32 {
+    if let Some(x) = report.get(report.len()/2) {
+        *x
+    } else {
+        0
+    }
+}
+
+fn relevant_rules(report: &[i32], rules: &[(i32, i32)]) -> Vec<(i32, i32)> {
+    let mut relevant: Vec<(i32, i32)> = Vec::new();
+    for rule in rules.iter() {
+        let (x, y) = rule;
+        if report.contains(x) && report.contains(y) {
+            relevant.push(*rule);
+        }
+    }
+    relevant
+}
+
+fn sequence_from_rules(rules: &[(i32, i32)], original: &[i32]) -> Vec<i32> {
+    let mut rules = rules.to_vec();
+    let mut seq: Vec<i32> = Vec::new();
+    while !rules.is_empty() {
+        let prev_len = rules.len();
+        if rules.len() == 1 {
+            if original.contains(&rules[0].0) {seq.push(rules[0].0);}
+            if original.contains(&rules[0].1) {seq.push(rules[0].1);}
+            break;
+        }
+        let mut xs: Vec<i32> = Vec::new();
+        let mut ys: Vec<i32> = Vec::new();
+        for rule in rules.iter() {
+            if !xs.contains(&rule.0) {
+                xs.push(rule.0);
+            }
+            if !ys.contains(&rule.1) {
+                ys.push(rule.1);
+            }
+        }
+        for x in xs.iter() {
+            if !ys.contains(x) {
+                if original.contains(x) {seq.push(*x);}
+                for rule in rules.clone().iter() {
+                    if rule.0 == *x {
+                        if rules.len() == 1
+                            && original.contains(&rule.1) {seq.push(rule.1);}
+                        let i = rules.iter().position(|r| r == rule).expect("rule removal indexing failed");
+                        rules.remove(i);
+                    }
+                }
+            }
+        }
+        if rules.len() == prev_len {panic!("sequence_from_rules infinite loop");}
+    }
+    seq
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn scan_rules_() {
+        let s = "47|61\n75|61\n47|29\n75|13\n53|13\n\n75,47,61,53,29\n97,61,53,29,13\n";
+        let mut sc = Scanner::new(s);
+        assert_eq!(scan_rules(&mut sc), Ok(vec![(47, 61), (75, 61), (47, 29), (75, 13), (53, 13)]));
+        assert_eq!(sc.pos(), 31);
+    }
+
+    #[test]
+    fn scan_reports_() {
+        let s = "47|61\n75|61\n47|29\n75|13\n53|13\n\n75,47,61,53,29\n97,61,53,29,13\n";
+        let mut sc = Scanner::new(s);
+        sc.seek(31);
+        assert_eq!(scan_reports(&mut sc), Ok(vec![vec![75,47,61,53,29], vec![97,61,53,29,13]]));
+    }
+
+    #[test]
+    fn passes_rule_true() {
+        let rule = (47, 53);
+        let report = vec![75,47,61,53,29];
+        assert!(passes_rule(&report, &rule));
+    }
+
+    #[test]
+    fn passes_rule_false() {
+        let rule = (47, 53);
+        let report = vec![75,53,47,61,29];
+        assert!(!passes_rule(&report, &rule));
+    }
+
+    #[test]
+    fn middle_of_report_() {
+        let report = vec![75,53,47,61,29];
+        assert_eq!(middle_of_report(&report), 47);
+    }
+
+    #[test]
+    fn relevant_rules_() {
+        let rules = vec![(47, 53), (26, 44), (27, 98), (4, 61), (75, 61), (23, 16), (42, 29)];
+        let report = vec![75,53,47,61,29];
+        assert_eq!(relevant_rules(&report, &rules), vec![(47, 53), (75, 61)]);
+    }
+
+    #[test]
+    fn sequence_from_rules_() {
+        let rules = vec![(47, 53), (21, 47), (21, 53), (47, 99), (53, 99)];
+        let original = vec![47, 53, 21, 99];
+        assert_eq!(sequence_from_rules(&rules, &original), vec![21, 47, 53, 99]);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,605 @@
+use std::str::FromStr;
+use aoc_common::{grid::Pos, render::{self, Frame, Tile}, Answer, Direction, Grid, ParseError, Solution};
+
+#[derive(Clone, Debug)]
+pub struct Map {
+    obstacles: Grid<bool>,
+    guard: Guard,
+    history: Vec<(Pos, Direction)>
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let mut guards: Vec<Guard> = Vec::new();
+        let obstacles = Grid::parse(s, "'.', '#' or the guard", |loc, c| match c {
+            '.' => Some(false),
+            '#' => Some(true),
+            c => Direction::from_arrow(c).map(|dir| {
+                guards.push(Guard { loc, dir });
+                false
+            })
+        })?;
+        if let Some(Guard { loc: (i, j), .. }) = guards.get(1) {
+            return Err(ParseError::invalid(i + 1, j + 1, "more than one guard"));
+        }
+        let guard = guards.pop().ok_or_else(|| ParseError::invalid(obstacles.rows() + 1, 1, "guard not found"))?;
+        Ok(Self {
+            obstacles,
+            history: vec![(guard.loc, guard.dir)],
+            guard
+        })
+    }
+}
+
+impl Map {
+    fn advance(&mut self) -> Result<bool, ()> {
+        let Some(ahead) = self.obstacles.step(self.guard.loc, self.guard.dir) else {return Ok(true);};
+        if !self.obstacles[ahead] {
+            self.guard.loc = ahead;
+        } else {
+            self.guard.dir = self.guard.dir.turn_right();
+        }
+        if self.history.contains(&self.guard_status()) {return Err(());}
+        self.history.push(self.guard_status());
+        Ok(false)
+    }
+
+    fn advance_all(&mut self) -> Result<(), ()> {
+        while !self.advance()? {}
+        Ok(())
+    }
+
+    fn guard_status(&self) -> (Pos, Direction) {
+        (self.guard.loc, self.guard.dir)
+    }
+
+    // Obstacles, with each visited square showing the way the guard last left it.
+    fn frame(&self) -> Frame {
+        let mut frame = self.obstacles.map(|&o| if o {Tile::new('#', render::WALL)} else {Tile::new('.', render::DIM)});
+        for &(loc, dir) in self.history.iter() {
+            frame[loc] = Tile::new(dir.arrow(), render::ORANGE);
+        }
+        frame
+    }
+
+    // As `frame`, after `advance` found a loop: the steps from the repeated
+    // one onwards go round forever, so they're drawn in red.
+    fn loop_frame(&self) -> Frame {
+        let mut frame = self.frame();
+        let start = self.history.iter().position(|&s| s == self.guard_status()).unwrap_or(self.history.len());
+        for &(loc, dir) in self.history[start..].iter() {
+            frame[loc] = Tile::new(dir.arrow(), render::RED);
+        }
+        frame
+    }
+
+    fn squares_visited(&self) -> Vec<Pos> {
+        let mut visited: Vec<Pos> = Vec::new();
+        for (square, _) in self.history.iter() {
+            if !visited.contains(square) {
+                visited.push(*square);
+            }
+        }
+        visited
+    }
+
+    fn advance_check_loops(&mut self) -> Result<(bool, Option<Pos>), ()> {
+        let Some(ahead) = self.obstacles.step(self.guard.loc, self.guard.dir) else {return Ok((true, None));};
+        let mut looping_obst: Option<Pos> = None;
+        if !self.obstacles[ahead] {
+            let mut modified = self.clone();
+            modified.obstacles[ahead] = true;
+            if modified.advance_all().is_err() {looping_obst = Some(ahead);}
+            self.guard.loc = ahead;
+        } else {
+            self.guard.dir = self.guard.dir.turn_right();
+        }
+        if self.history.contains(&self.guard_status()) {return Err(());}
+        self.history.push(self.guard_status());
+        Ok((false, looping_obst))
+    }
+
+    fn advance_all_check_loops(&mut self) -> Result<Vec<Pos>, ()> {
+        let mut loop_vec: Vec<Pos> = vec![];
+        loop {
+            match self.advance_check_loops()? {
+                (false, Some(looping_obst)) => {loop_vec.push(looping_obst);},
+                (false, None) => {},
+                (true, _) => {break;}
+            }
+        }
+        Ok(loop_vec)
+    }
+
+    #[allow(dead_code)]
+    fn looping_obstacles(&self) -> Vec<Pos> {
+        let mut obsts: Vec<Pos> = Vec::new();
+        for pos in self.obstacles.positions() {
+            if !self.obstacles[pos] {
+                let mut modified = self.clone();
+                modified.obstacles[pos] = true;
+                if let Err(()) = modified.advance_all() {
+                    obsts.push(pos);
+                }
+            }
+        }
+        obsts
+    }
+
+    // fn find_all_loops(&self) -> Vec<(i32, i32)> {                        // This is an abandoned method of finding obstacle locations
+    //     let mut loops: Vec<(i32, i32)> = Vec::new();                     // that would cause loops. It could only find simple square
+    //     for obstacle in self.obstacles.iter() {                          // loops. It seems there is no limit to how complex a loop could
+    //         if let Some(point) = self.find_as_top(*obstacle) {           // be theoretically, which is why I abandoned the
+    //             if !loops.contains(&point) {loops.push(point);}          // pattern-recognition approach.
+    //         }
+    //         if let Some(point) = self.find_as_right(*obstacle) {
+    //             if !loops.contains(&point) {loops.push(point);}
+    //         }
+    //         if let Some(point) = self.find_as_bottom(*obstacle) {
+    //             if !loops.contains(&point) {loops.push(point);}
+    //         }
+    //         if let Some(point) = self.find_as_left(*obstacle) {
+    //             if !loops.contains(&point) {loops.push(point);}
+    //         }
+    //     }
+    //     loops
+    // }
+
+    // fn find_as_top(&self, obstacle: (i32, i32)) -> Option<(i32, i32)> {
+    //     let (x, y) = obstacle;
+    //     if x < self.height - 2 && y >= 1 && y < self.width - 1 {
+    //         let mut right: Option<(i32, i32)> = None;
+    //         let mut left: Option<(i32, i32)> = None;
+    //         for candidate in self.obstacles.iter() {
+    //             if candidate.0 == x + 1 && candidate.1 > y {
+    //                 if let Some(other) = right {
+    //                     if candidate.1 < other.1 {
+    //                         right = Some(*candidate);
+    //                     }
+    //                 } else {
+    //                     right = Some(*candidate);
+    //                 }
+    //             }
+    //             if candidate.1 == y - 1 && candidate.0 > x {
+    //                 if let Some(other) = left {
+    //                     if candidate.0 < other.0 {
+    //                         left = Some(*candidate);
+    //                     }
+    //                 } else {
+    //                     left = Some(*candidate);
+    //                 }
+    //             }
+    //         }
+    //         if let (Some(r), Some(l)) = (right, left) {
+    //             let b = (l.0 + 1, r.1 - 1);
+    //             if self.is_unobstructed_loop(obstacle, r, b, l) {
+    //                 return Some(b);
+    //             }
+    //         }
+    //     }
+    //     None
+    // }
+
+    // fn find_as_right(&self, obstacle: (i32, i32)) -> Option<(i32, i32)> {
+    //     let (x, y) = obstacle;
+    //     if y >= 2 && x >= 1 && x < self.height - 1 {
+    //         let mut top: Option<(i32, i32)> = None;
+    //         let mut bottom: Option<(i32, i32)> = None;
+    //         for candidate in self.obstacles.iter() {
+    //             if candidate.0 == x - 1 && candidate.1 < y {
+    //                 if let Some(other) = top {
+    //                     if candidate.1 > other.1 {
+    //                         top = Some(*candidate);
+    //                     }
+    //                 } else {
+    //                     top = Some(*candidate);
+    //                 }
+    //             }
+    //             if candidate.1 == y - 1 && candidate.0 > x {
+    //                 if let Some(other) = bottom {
+    //                     if candidate.0 < other.0 {
+    //                         bottom = Some(*candidate);
+    //                     }
+    //                 } else {
+    //                     bottom = Some(*candidate);
+    //                 }
+    //             }
+    //         }
+    //         if let (Some(t), Some(b)) = (top, bottom) {
+    //             let l = (b.0 - 1, t.1 - 1);
+    //             if self.is_unobstructed_loop(t, obstacle, b, l) {
+    //                 return Some(l);
+    //             }
+    //         }
+    //     }
+    //     None
+    // }
+
+    // fn find_as_bottom(&self, obstacle: (i32, i32)) -> Option<(i32, i32)> {
+    //     let (x, y) = obstacle;
+    //     if x >= 2 && y >= 1 && y < self.width - 1 {
+    //         let mut right: Option<(i32, i32)> = None;
+    //         let mut left: Option<(i32, i32)> = None;
+    //         for candidate in self.obstacles.iter() {
+    //             if candidate.1 == y + 1 && candidate.0 < x {
+    //                 if let Some(other) = right {
+    //                     if candidate.0 > other.0 {
+    //                         right = Some(*candidate);
+    //                     }
+    //                 } else {
+    //                     right = Some(*candidate);
+    //                 }
+    //             }
+    //             if candidate.0 == x - 1 && candidate.1 < y {
+    //                 if let Some(other) = left {
+    //                     if candidate.1 > other.1 {
+    //                         left = Some(*candidate);
+    //                     }
+    //                 } else {
+    //                     left = Some(*candidate);
+    //                 }
+    //             }
+    //         }
+    //         if let (Some(r), Some(l)) = (right, left) {
+    //             let t = (r.0 - 1, l.1 + 1);
+    //             if self.is_unobstructed_loop(t, r, obstacle, l) {
+    //                 return Some(t);
+    //             }
+    //         }
+    //     }
+    //     None
+    // }
+
+    // fn find_as_left(&self, obstacle: (i32, i32)) -> Option<(i32, i32)> {
+    //     let (x, y) = obstacle;
+    //     if y < self.width - 2 && x >= 1 && x < self.height - 1 {
+    //         let mut top: Option<(i32, i32)> = None;
+    //         let mut bottom: Option<(i32, i32)> = None;
+    //         for candidate in self.obstacles.iter() {
+    //             if candidate.1 == y + 1 && candidate.0 < x {
+    //                 if let Some(other) = top {
+    //                     if candidate.0 > other.0 {
+    //                         top = Some(*candidate);
+    //                     }
+    //                 } else {
+    //                     top = Some(*candidate);
+    //                 }
+    //             }
+    //             if candidate.0 == x + 1 && candidate.1 > y {
+    //                 if let Some(other) = bottom {
+    //                     if candidate.1 < other.1 {
+    //                         bottom = Some(*candidate);
+    //                     }
+    //                 } else {
+    //                     bottom = Some(*candidate);
+    //                 }
+    //             }
+    //         }
+    //         if let (Some(t), Some(b)) = (top, bottom) {
+    //             let r = (t.0 + 1, b.1 + 1);
+    //             if self.is_unobstructed_loop(t, r, b, obstacle) {
+    //                 return Some(r);
+    //             }
+    //         }
+    //     }
+    //     None
+    // }
+
+    // fn is_unobstructed_loop(&self, top: (i32, i32), right: (i32, i32), bottom: (i32, i32), left: (i32, i32)) -> bool {
+    //     for i in top.1..right.1 {
+    //         if self.obstacles.contains(&(right.0, i)) {return false;}
+    //         if self.obstacles.contains(&(left.0, i)) {return false;}
+    //     }
+    //     for i in right.0+1..bottom.0-1 {
+    //         if self.obstacles.contains(&(i, bottom.1)) {return false;}
+    //         if self.obstacles.contains(&(i, top.1)) {return false;}
+    //     }
+    //     true
+    // }
+}
+
+#[derive(Clone, Debug)]
+struct Guard {
+    loc: Pos,
+    dir: Direction
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = Map;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        Map::from_str(s)
+    }
+
+    fn part1(map: &Self::Input) -> Result<Answer, String> {
+        part1(map).map(Answer::from)
+    }
+
+    fn part2(map: &Self::Input) -> Result<Answer, String> {
+        part2(map).map(Answer::from)
+    }
+
+    // Both parts show the guard's patrol, with any loop in red.
+    fn render(map: &Self::Input, _part: u8) -> Option<Frame> {
+        let mut m = map.clone();
+        Some(match m.advance_all() {
+            Ok(()) => m.frame(),
+            Err(()) => m.loop_frame()
+        })
+    }
+
+    // One frame per step of the patrol, for both parts.
+    fn animate(map: &Self::Input, _part: u8, frame: &mut dyn FnMut(&Frame)) -> bool {
+        let mut m = map.clone();
+        frame(&m.frame());
+        while let Ok(false) = m.advance() {
+            frame(&m.frame());
+        }
+        true
+    }
+}
+
+const LOOPS: &str = "the guard walks in a loop and never leaves the map";
+
+fn part1(map: &Map) -> Result<usize, String> {
+    let mut m = map.clone();
+    m.advance_all().map_err(|()| LOOPS.to_string())?;
+    Ok(m.squares_visited().len())
+}
+
+fn part2(map: &Map) -> Result<usize, String> {  // Very ineffecient as is. Took about an hour with AoC input.
+    let mut m = map.clone();
+    m.advance_all_check_loops().map(|v| v.len()).map_err(|()| LOOPS.to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_str() {
+        let s = include_str!("../../examples/day06/guard.txt");
+        let m = Map::from_str(s).unwrap();
+        assert_eq!(m.obstacles.cols(), 10);
+        assert_eq!(m.obstacles.rows(), 10);
+        let obstacles: Vec<Pos> = m.obstacles.iter().filter(|(_, &o)| o).map(|(pos, _)| pos).collect();
+        assert_eq!(obstacles, vec![(0, 4), (1, 9), (3, 2), (4, 7), (6, 1), (7, 8), (8, 0), (9, 6)]);
+        assert_eq!(m.guard.loc, (6, 4));
+        assert_eq!(m.guard.dir, Direction::Up);
+        let m = Map::from_str("..#.\n.<..\n").unwrap();
+        assert_eq!((m.guard.loc, m.guard.dir), ((1, 1), Direction::Left));
+    }
+
+    #[test]
+    fn from_str_err() {
+        let e = Map::from_str("..#.\n.^.x\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.found()), (2, 4, Some('x')));
+        let e = Map::from_str("..#.\n.^.\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 4));
+        assert!(Map::from_str("..#.\n....\n").is_err());
+        let e = Map::from_str(".^\n^.\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+    }
+
+    #[test]
+    fn advance() {
+        let s = include_str!("../../examples/day06/guard.txt");
+        let mut m = Map::from_str(s).unwrap();
+        m.advance().unwrap();
+        assert_eq!(m.guard.loc, (5, 4));
+        assert_eq!(m.guard.dir, Direction::Up);
+    }
+
+    #[test]
+    fn advance_all_ok() {
+        let s = include_str!("../../examples/day06/guard.txt");
+        let mut m = Map::from_str(s).unwrap();
+        m.advance_all().unwrap();
+        assert_eq!(m.squares_visited().len(), 41);
+    }
+
+    #[test]
+    fn frame() {
+        let mut m = Map::from_str(".#..\n.^.#\n....\n").unwrap();
+        m.advance_all().unwrap();
+        assert_eq!(render::text(&m.frame()), ".#..\n.>v#\n..v.\n");
+    }
+
+    #[test]
+    fn animate() {
+        let map = Map::from_str(".#..\n.^.#\n....\n").unwrap();
+        let mut frames = vec![];
+        assert!(Day06::animate(&map, 1, &mut |f| frames.push(render::text(f))));
+        assert_eq!(frames, vec![".#..\n.^.#\n....\n", ".#..\n.>.#\n....\n", ".#..\n.>>#\n....\n", ".#..\n.>v#\n....\n", ".#..\n.>v#\n..v.\n"]);
+    }
+
+    #[test]
+    fn advance_all_err() {
+        let s = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+......#.#.
+#.........
+......#...\n";
+        let mut m = Map::from_str(s).unwrap();
+        if let Ok(()) = m.advance_all() {panic!();}
+    }
+
+    #[test]
+    fn render_loop() {
+        let map = Map::from_str(".#..\n...#\n#^..\n..#.\n").unwrap();
+        let frame = Day06::render(&map, 1).unwrap();
+        assert_eq!(render::text(&frame), ".#..\n.>v#\n#<<.\n..#.\n");
+        assert_eq!(frame.iter().filter(|(_, t)| t.color == render::RED).count(), 4);
+    }
+
+    #[test]
+    fn parts_loop() {
+        let map = Map::from_str(".#..\n...#\n#^..\n..#.\n").unwrap();
+        assert_eq!(part1(&map), Err(LOOPS.to_string()));
+        assert_eq!(part2(&map), Err(LOOPS.to_string()));
+    }
+
+//     #[test]
+//     fn find_as_top_some() {
+//         let s = "....#.....
+// .........#
+// ..........
+// ..#.......
+// .......#..
+// ..........
+// .#..^.....
+// ........#.
+// #.........
+// ......#...\n";
+//         let m = Map::from_str(s).unwrap();
+//         assert_eq!(m.find_as_top((3, 2)), Some((7, 6)));
+//     }
+
+//     #[test]
+//     fn find_as_top_none() {
+//         let s = "....#.....
+// .........#
+// ..........
+// ..#.......
+// .......#..
+// ..........
+// #...^.....
+// ........#.
+// #.........
+// ......#...\n";
+//         let m = Map::from_str(s).unwrap();
+//         assert_eq!(m.find_as_top((3, 2)), None);
+//     }
+
+//     #[test]
+//     fn find_as_right_some() {
+//         let s = "....#.....
+// .........#
+// ..........
+// ..#.......
+// .......#..
+// ..........
+// .#..^.....
+// ........#.
+// #.........
+// ......#...\n";
+//         let m = Map::from_str(s).unwrap();
+//         assert_eq!(m.find_as_right((1, 9)), Some((6, 3)));
+//     }
+
+//     #[test]
+//     fn find_as_right_none() {
+//         let s = "....#.....
+// .........#
+// ..........
+// ..#.......
+// .......#..
+// ..........
+// .#..^.....
+// ........#.
+// #.........
+// ......#...\n";
+//         let m = Map::from_str(s).unwrap();
+//         assert_eq!(m.find_as_right((7, 8)), None);
+//     }
+
+//     #[test]
+//     fn find_as_bottom_some() {
+//         let s = "....#.....
+// .........#
+// ..........
+// ..........
+// .......#..
+// ..........
+// .#..^.....
+// ........#.
+// #.........
+// ......#...\n";
+//         let m = Map::from_str(s).unwrap();
+//         assert_eq!(m.find_as_bottom((7, 8)), Some((0, 2)));
+//     }
+
+//     #[test]
+//     fn find_as_bottom_none() {
+//         let s = "....#.....
+// .........#
+// ..........
+// ..#.......
+// .......#..
+// ..........
+// .#..^.....
+// ........#.
+// #.........
+// ......#...\n";
+//         let m = Map::from_str(s).unwrap();
+//         assert_eq!(m.find_as_bottom((7, 8)), None);
+//     }
+
+//     #[test]
+//     fn find_as_left_some() {
+//         let s = "....#.....
+// .........#
+// ..........
+// ..#.......
+// ..........
+// ..........
+// .#..^.....
+// ........#.
+// #.........
+// ......#...\n";
+//         let m = Map::from_str(s).unwrap();
+//         assert_eq!(m.find_as_left((6, 1)), Some((4, 9)));
+//     }
+
+//     #[test]
+//     fn find_as_left_none() {
+//         let s = "....#.....
+// .........#
+// ..........
+// ..#.......
+// .......#..
+// ..........
+// .#..^.....
+// ........#.
+// #.........
+// ......#...\n";
+//         let m = Map::from_str(s).unwrap();
+//         assert_eq!(m.find_as_left((6, 1)), None);
+//     }
+
+//     #[test]
+//     fn find_all_loops() {
+//         let s = "....#.....
+// .........#
+// ..........
+// ..#.......
+// .......#..
+// ..........
+// .#..^.....
+// ........#.
+// #.........
+// ......#...\n";
+//         let m = Map::from_str(s).unwrap();
+//         let loops = m.find_all_loops();
+//         println!("{:?}", loops);
+//         assert_eq!(loops.len(), 6);
+//     }
+
+    #[test]
+    fn looping_obstacles() {
+        let s = include_str!("../../examples/day06/guard.txt");
+        let m = Map::from_str(s).unwrap();
+        let obsts = m.looping_obstacles();
+        assert_eq!(obsts.len(), 6);
+    }
+}
\ No newline at end of file
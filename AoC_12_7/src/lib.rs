@@ -0,0 +1,166 @@
+use aoc_common::{Answer, ParseError, Scanner, ScanError, Solution};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Equation {
+    value: i64,
+    operands: Vec<i64>
+}
+
+// impl Equation {
+//     fn solve(&mut self) -> Result<(), ()> {
+
+//     }
+// }
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum Operator {
+    Mul, Add, Conc
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input = Vec<Equation>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        scan_equations(s).map_err(|e| e.locate(s))
+    }
+
+    fn part1(equations: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(equations).into())
+    }
+
+    fn part2(equations: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(equations).into())
+    }
+}
+
+fn part1(equations: &[Equation]) -> i64 {
+    let mut calibration = 0;
+    for eq in equations.iter() {
+        if let Ok(ops) = match_oper(eq.value, &eq.operands) {
+            if !ops.contains(&Operator::Conc) {
+                calibration += eq.value;
+            }
+        }
+    }
+    calibration
+}
+
+fn part2(equations: &[Equation]) -> i64 {
+    let mut calibration = 0;
+    for eq in equations.iter() {
+        if match_oper(eq.value, &eq.operands).is_ok() {
+            calibration += eq.value;
+        }
+    }
+    calibration
+}
+
+fn scan_equations(s: &str) -> Result<Vec<Equation>, ScanError> {
+    let mut equations: Vec<Equation> = Vec::new();
+    let mut sc = Scanner::new(s);
+    while !sc.at_end() {
+        let value = sc.num()?;
+        let mut operands: Vec<i64> = Vec::new();
+        sc.char(':')?;
+        while sc.char(' ').is_ok() {
+            operands.push(sc.num()?);
+        }
+        if !sc.at_end() {
+            sc.line_ending()?;
+        }
+        equations.push(Equation { value, operands });
+    }
+    Ok(equations)
+}
+
+fn match_oper(value: i64, operands: &[i64]) -> Result<Vec<Operator>, ()> {
+    if operands.len() < 2 {
+        panic!("match_oper call on vec len <2");
+    } else if operands.len() == 2 {
+        if operands[0] + operands[1] == value {
+            Ok(vec![Operator::Add])
+        } else if operands[0] * operands[1] == value {
+            Ok(vec![Operator::Mul])
+        } else {
+            let mut r = operands[1];
+            let mut mul_factor = 10;
+            loop {
+                r /= 10;
+                if r == 0 {break;}
+                else {mul_factor *= 10;}
+            }
+            if operands[0] * mul_factor + operands[1] == value {
+                return Ok(vec![Operator::Conc]);
+            }
+            Err(())
+        }
+    } else {
+        let mut ops_mul = operands.to_vec();
+        ops_mul[1] *= ops_mul[0];
+        ops_mul.remove(0);
+        if let Ok(v) = match_oper(value, &ops_mul) {
+            let mut all_ops = vec![Operator::Mul];
+            all_ops.extend(v.iter());
+            return Ok(all_ops);
+        }
+        let mut ops_add = operands.to_vec();
+        ops_add[1] += ops_add[0];
+        ops_add.remove(0);
+        if let Ok(v) = match_oper(value, &ops_add) {
+            let mut all_ops = vec![Operator::Add];
+            all_ops.extend(v.iter());
+            return Ok(all_ops);
+        }
+        let mut ops_conc = operands.to_vec();
+        let mut r = ops_conc[1];
+        let mut mul_factor = 10;
+        loop {
+            r /= 10;
+            if r == 0 {break;}
+            else {mul_factor *= 10;}
+        }
+        ops_conc[1] += ops_conc[0] * mul_factor;
+        ops_conc.remove(0);
+        if let Ok(v) = match_oper(value, &ops_conc) {
+            let mut all_ops = vec![Operator::Conc];
+            all_ops.extend(v.iter());
+            return Ok(all_ops);
+        }
+        Err(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn scan_equations_() {
+        let s = "3: 1 1 1
+10: 5 2";
+        assert_eq!(scan_equations(s).unwrap(), vec![Equation { value: 3, operands: vec![1, 1, 1], }, Equation { value: 10, operands: vec![5, 2], }]);
+    }
+
+    #[test]
+    fn match_oper_2() {
+        use Operator::*;
+        let e = Equation { value: 6, operands: vec![2, 3], };
+        assert_eq!(match_oper(e.value, &e.operands).unwrap(), vec![Mul]);
+    }
+
+    #[test]
+    fn match_oper_3() {
+        use Operator::*;
+        let e = Equation { value: 15, operands: vec![3, 2, 3], };
+        assert_eq!(match_oper(e.value, &e.operands).unwrap(), vec![Add, Mul]);
+    }
+
+    #[test]
+    fn match_oper_4() {
+        let e = Equation { value: 15, operands: vec![3, 2, 3, 9], };
+        assert_eq!(match_oper(e.value, &e.operands), Err(()));
+    }
+}
\ No newline at end of file
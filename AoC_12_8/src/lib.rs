@@ -0,0 +1,228 @@
+use std::str::FromStr;
+use aoc_common::{grid::Pos, Answer, Grid, ParseError, Solution};
+
+#[derive(Clone, Debug)]
+pub struct Map {
+    signals: Vec<Signal>,
+    antinodes: Grid<bool>
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let mut signals = Vec::new();
+        let antinodes = Grid::parse(s, "'.' or an antenna", |loc, c| match c {
+            '.' => Some(false),
+            c if c.is_ascii_alphanumeric() => {
+                signals.push(Signal { loc, c });
+                Some(false)
+            },
+            _ => None
+        })?;
+
+        Ok(Self {
+            signals,
+            antinodes
+        })
+    }
+}
+
+impl Map {
+    
+    fn pairs(&self, c: char) -> Vec<(Signal, Signal)> {
+        let mut c_signals: Vec<Signal> = Vec::new();
+        let mut pairs: Vec<(Signal, Signal)> = Vec::new();
+        for signal in self.signals.iter() {
+            if signal.c == c {
+                c_signals.push(*signal);
+            }
+        }
+        while c_signals.len() > 1 {
+            let s = c_signals.pop().unwrap();
+            for other in c_signals.iter() {
+                pairs.push((s, *other));
+            }
+        }
+        pairs
+    }
+
+    fn chars_used(&self) -> Vec<char> {
+        let mut chars: Vec<char> = Vec::new();
+        for signal in self.signals.iter() {
+            let c = signal.c;
+            if !chars.contains(&c) {chars.push(c)}
+        }
+        chars
+    }
+
+    fn place_antinodes_1(&mut self) {
+        let chars = self.chars_used();
+        for c in chars.iter() {
+            let c_pairs = self.pairs(*c);
+            for pair in c_pairs.iter() {
+                let (back, forward) = steps(pair);
+                if let Some(an1) = self.antinodes.offset(pair.0.loc, back) {self.antinodes[an1] = true;}
+                if let Some(an2) = self.antinodes.offset(pair.1.loc, forward) {self.antinodes[an2] = true;}
+            }
+        }
+    }
+
+    fn place_antinodes_2(&mut self) {
+        let chars = self.chars_used();
+        for c in chars.iter() {
+            let c_pairs = self.pairs(*c);
+            for pair in c_pairs.iter() {
+                let (back, forward) = steps(pair);
+                let mut an1 = Some(pair.0.loc);
+                while let Some(an) = an1 {
+                    self.antinodes[an] = true;
+                    an1 = self.antinodes.offset(an, back);
+                }
+                let mut an2 = Some(pair.1.loc);
+                while let Some(an) = an2 {
+                    self.antinodes[an] = true;
+                    an2 = self.antinodes.offset(an, forward);
+                }
+            }
+        }
+    }
+
+    fn antinode_count(&self) -> usize {
+        self.antinodes.iter().filter(|(_, &an)| an).count()
+    }
+}
+
+// Steps from the first signal away from the second, and from the second away
+// from the first.
+fn steps(pair: &(Signal, Signal)) -> ((isize, isize), (isize, isize)) {
+    let dx = pair.1.loc.0 as isize - pair.0.loc.0 as isize;
+    let dy = pair.1.loc.1 as isize - pair.0.loc.1 as isize;
+    ((-dx, -dy), (dx, dy))
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Signal {
+    loc: Pos,
+    c: char
+}
+
+impl Signal {
+    #![allow(dead_code)]
+    fn from(x: usize, y: usize, c: char) -> Self {
+        Self {
+            loc: (x, y),
+            c
+        }
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = Map;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        Map::from_str(s)
+    }
+
+    fn part1(map: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(map).into())
+    }
+
+    fn part2(map: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(map).into())
+    }
+}
+
+fn part1(map: &Map) -> usize {
+    let mut m = map.clone();
+    m.place_antinodes_1();
+    m.antinode_count()
+}
+
+fn part2(map: &Map) -> usize {
+    let mut m = map.clone();
+    m.place_antinodes_2();
+    m.antinode_count()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn map_from_str_err() {
+        let e = Map::from_str("..abc.
+......
+1234567
+......
+").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 7));
+        let e = Map::from_str("..a#..\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.found()), (1, 4, Some('#')));
+    }
+
+    #[test]
+    fn map_from_str() {
+        let m = Map::from_str("..abc.
+......
+123456
+......
+").unwrap();
+        assert_eq!(m.signals, vec![Signal::from(0, 2, 'a'), Signal::from(0, 3, 'b'), Signal::from(0, 4, 'c'), 
+Signal::from(2, 0, '1'), Signal::from(2, 1, '2'), Signal::from(2, 2, '3'), Signal::from(2, 3, '4'),
+Signal::from(2, 4, '5'), Signal::from(2, 5, '6')]);
+        assert_eq!(m.antinodes.rows(), 4);
+        assert_eq!(m.antinodes.cols(), 6);
+    }
+
+    #[test]
+    fn pairs_() {
+        let m = Map::from_str("..abc.
+..a..a
+......
+.a....
+").unwrap();
+        let pairs = m.pairs('a');
+        assert_eq!(pairs.len(), 6);
+        assert!(pairs.contains(&(Signal::from(0, 2, 'a'), Signal::from(1, 2, 'a'))) || pairs.contains(&(Signal::from(1, 2, 'a'), Signal::from(0, 2, 'a'))));
+        assert!(pairs.contains(&(Signal::from(0, 2, 'a'), Signal::from(1, 5, 'a'))) || pairs.contains(&(Signal::from(1, 5, 'a'), Signal::from(0, 2, 'a'))));
+        assert!(pairs.contains(&(Signal::from(0, 2, 'a'), Signal::from(3, 1, 'a'))) || pairs.contains(&(Signal::from(3, 1, 'a'), Signal::from(0, 2, 'a'))));
+        assert!(pairs.contains(&(Signal::from(1, 2, 'a'), Signal::from(1, 5, 'a'))) || pairs.contains(&(Signal::from(1, 5, 'a'), Signal::from(1, 2, 'a'))));
+        assert!(pairs.contains(&(Signal::from(1, 2, 'a'), Signal::from(3, 1, 'a'))) || pairs.contains(&(Signal::from(3, 1, 'a'), Signal::from(1, 2, 'a'))));
+        assert!(pairs.contains(&(Signal::from(1, 5, 'a'), Signal::from(3, 1, 'a'))) || pairs.contains(&(Signal::from(3, 1, 'a'), Signal::from(1, 5, 'a'))));
+    }
+
+    #[test]
+    fn part1_1() {
+        let mut m = Map::from_str("...b..
+..a.ab
+...ab.
+......
+").unwrap();
+        m.place_antinodes_1();
+        assert_eq!(m.antinode_count(), 6);
+        assert!(m.antinodes[(0, 1)]);
+        assert!(m.antinodes[(0, 5)]);
+        assert!(m.antinodes[(1, 0)]);
+        assert!(m.antinodes[(3, 2)]);
+        assert!(m.antinodes[(3, 4)]);
+        assert!(m.antinodes[(3, 3)]);
+    }
+
+    #[test]
+    fn part1_2() {
+        let mut m = Map::from_str(include_str!("../../examples/day08/antennas.txt")).unwrap();
+        m.place_antinodes_1();
+        assert_eq!(m.antinode_count(), 14);
+    }
+
+    #[test]
+    fn part2() {
+        let mut m = Map::from_str(include_str!("../../examples/day08/antennas.txt")).unwrap();
+        m.place_antinodes_2();
+        assert_eq!(m.antinode_count(), 34);
+    }
+}
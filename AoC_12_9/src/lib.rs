@@ -0,0 +1,171 @@
+use aoc_common::{Answer, ParseError, Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = Vec<Option<u32>>;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError> {
+        to_block_vec(s)
+    }
+
+    fn part1(blocks: &Self::Input) -> Result<Answer, String> {
+        Ok(part1(blocks).into())
+    }
+
+    fn part2(blocks: &Self::Input) -> Result<Answer, String> {
+        Ok(part2(blocks).into())
+    }
+}
+
+fn part1(blocks: &[Option<u32>]) -> u64 {
+    let mut v = blocks.to_vec();
+    move_blocks(&mut v);
+    checksum(&v)
+}
+
+fn part2(blocks: &[Option<u32>]) -> u64 {
+    let mut v = blocks.to_vec();
+    move_files(&mut v);
+    checksum(&v)
+}
+
+fn to_block_vec(s: &str) -> Result<Vec<Option<u32>>, ParseError> {
+    let mut v = Vec::new();
+    for (i, c) in s.trim_end().chars().enumerate() {
+        let n = c.to_digit(10).ok_or_else(|| ParseError::unexpected(1, i + 1, "a digit", c))?;
+        let block = match i % 2 {
+            0 => Some(i as u32 / 2),
+            _ => None
+        };
+        for _ in 0..n {
+            v.push(block);
+        }
+    }
+    Ok(v)
+}
+
+fn move_blocks(v: &mut Vec<Option<u32>>) {
+    let mut empty_i = 0;
+    loop {
+        if empty_i >= v.len() {break;}
+        if v[empty_i].is_some() {empty_i += 1;}
+        else {
+            if let Some(Some(x)) = v.pop() {
+                v[empty_i] = Some(x);
+                empty_i += 1;
+            }
+        }
+    }
+}
+
+fn move_files(v: &mut [Option<u32>]) {
+    let mut i = v.len() - 1;
+    loop {
+        if let Some(id) = v[i] {
+            let mut width = 1;
+            loop {
+                if width > i {return;}
+                if v[i-width] == Some(id) {width += 1;}
+                else {break;}
+            };
+            i -= width;
+            if let Some(i_to) = find_vacancy(v, width, i+1) {
+                transpose_blocks(v, i+1, i_to, width);
+            }
+        } else {
+            if i == 0 {return;}
+            i -= 1;
+        }
+    }
+}
+
+fn find_vacancy(v: &[Option<u32>], width: usize, left_of: usize) -> Option<usize> {
+    let mut empties = 0;
+    for (i, elem) in v[0..left_of].iter().enumerate() {
+        if elem.is_none() {empties += 1;}
+        else {empties = 0;}
+        if empties == width {return Some(i+1-width);}
+    }
+    None
+}
+
+fn checksum(v: &[Option<u32>]) -> u64 {
+    let mut sum = 0;
+    for (i, opt) in v.iter().enumerate() {
+        if let Some(x) = *opt {
+            sum += (i as u32 * x) as u64;
+        }
+    }
+    sum
+}
+
+fn transpose_blocks(v: &mut [Option<u32>], i_from: usize, i_to: usize, n: usize) {
+    let mut n = n;
+    let mut i_from = i_from;
+    let mut i_to = i_to;
+    while n > 0 {
+        if v[i_to].is_some() {panic!("Errant transpose");}
+        v[i_to] = v[i_from];
+        v[i_from] = None;
+        i_to += 1;
+        i_from += 1;
+        n -= 1;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn to_block_vec_() {
+        let s = "123110312\n";
+        let expected = vec![Some(0), None, None, Some(1), Some(1), Some(1), None, Some(2), Some(3), Some(3), Some(3), None, Some(4), Some(4)];
+        assert_eq!(to_block_vec(s), Ok(expected));
+        assert_eq!(to_block_vec("12a4").unwrap_err().column, 3);
+    }
+
+    #[test]
+    fn move_blocks_() {
+        let mut v = vec![Some(0), None, None, Some(1), Some(1), Some(1), None, Some(2), Some(3), Some(3), Some(3), None, Some(4), Some(4)];
+        move_blocks(&mut v);
+        let expected = vec![Some(0), Some(4), Some(4), Some(1), Some(1), Some(1), Some(3), Some(2), Some(3), Some(3)];
+        assert_eq!(v, expected);
+    }
+
+    #[test]
+    fn checksum_() {
+        let v = vec![Some(0), Some(4), Some(4), Some(1), Some(1), Some(1), Some(3), Some(2), Some(3), Some(3)];
+        assert_eq!(checksum(&v), 107);
+    }
+
+    #[test]
+    fn transpose_blocks_() {
+        let mut v = vec![Some(0), None, None, Some(1), Some(1), Some(1), None, Some(2), Some(2), Some(3), Some(3), None, Some(4), Some(4)];
+        let expected = vec![Some(0), Some(2), Some(2), Some(1), Some(1), Some(1), None, None, None, Some(3), Some(3), None, Some(4), Some(4)];
+        transpose_blocks(&mut v, 7, 1, 2);
+        assert_eq!(v, expected);
+    }
+
+    #[test]
+    fn find_vacancy_() {
+        let v = vec![Some(0), None, None, Some(1), Some(1), Some(1), None, Some(2), Some(2), Some(3), Some(3), None, None, None, Some(4), Some(4)];
+        assert_eq!(find_vacancy(&v, 2, 9), Some(1));
+        assert_eq!(find_vacancy(&v, 3, 9), None);
+    }
+
+    #[test]
+    fn move_files_() {
+        let mut v = vec![Some(0), Some(0), None, None, None, Some(1), Some(1), Some(1), None, None, None, Some(2), None,
+            None, None, Some(3), Some(3), Some(3), None, Some(4), Some(4), None, Some(5), Some(5), Some(5), Some(5), None, Some(6), Some(6),
+            Some(6), Some(6), None, Some(7), Some(7), Some(7), None, Some(8), Some(8), Some(8), Some(8), Some(9), Some(9)];
+        let expected = vec![Some(0), Some(0), Some(9), Some(9), Some(2), Some(1), Some(1), Some(1), Some(7), Some(7), Some(7),
+            None, Some(4), Some(4), None, Some(3), Some(3), Some(3), None, None, None, None, Some(5), Some(5), Some(5), Some(5), None,
+            Some(6), Some(6), Some(6), Some(6), None, None, None, None, None, Some(8), Some(8), Some(8), Some(8), None, None];
+        move_files(&mut v);
+        assert_eq!(v, expected);
+        assert_eq!(checksum(&v), 2858);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,133 @@
+use std::{fs::{self, File}, io::{self, BufWriter, Write}, path::{Path, PathBuf}};
+use crate::{bench, render::{self, Frame}};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FrameFormat {
+    #[default]
+    Text,
+    Ppm,
+    Png
+}
+
+impl FrameFormat {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "txt" => Some(FrameFormat::Text),
+            "ppm" => Some(FrameFormat::Ppm),
+            "png" => Some(FrameFormat::Png),
+            _ => None
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            FrameFormat::Text => "txt",
+            FrameFormat::Ppm => "ppm",
+            FrameFormat::Png => "png"
+        }
+    }
+}
+
+// Seconds between frames when the asciicast plays back.
+const FRAME_TIME: f64 = 0.1;
+
+// Writes frame_00000.txt (or .ppm/.png), frame_00001... into a directory, and
+// all of them as one asciicast, animation.cast, for `asciinema play`.
+pub struct Recorder {
+    dir: PathBuf,
+    every: usize,
+    format: FrameFormat,
+    steps: usize,
+    frames: usize,
+    skipped: Option<Frame>,
+    cast: Option<BufWriter<File>>,
+    error: Option<io::Error>
+}
+
+impl Recorder {
+    pub fn new(dir: &Path, every: usize, format: FrameFormat) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            every: every.max(1),
+            format,
+            steps: 0,
+            frames: 0,
+            skipped: None,
+            cast: None,
+            error: None
+        })
+    }
+
+    // Keeps one step in `every`, plus the last one. The first write error
+    // stops recording and is returned by `finish`.
+    pub fn record(&mut self, frame: &Frame) {
+        let step = self.steps;
+        self.steps += 1;
+        if self.error.is_some() {return;}
+        if !step.is_multiple_of(self.every) {
+            self.skipped = Some(frame.clone());
+            return;
+        }
+        self.skipped = None;
+        if let Err(e) = self.write(frame) {self.error = Some(e);}
+    }
+
+    fn write(&mut self, frame: &Frame) -> io::Result<()> {
+        let path = self.dir.join(format!("frame_{:05}.{}", self.frames, self.format.extension()));
+        render::save(frame, &path, render::DEFAULT_SCALE)?;
+        if self.cast.is_none() {
+            let mut cast = BufWriter::new(File::create(self.dir.join("animation.cast"))?);
+            writeln!(cast, "{{\"version\": 2, \"width\": {}, \"height\": {}}}", frame.cols(), frame.rows())?;
+            writeln!(cast, "[0.000, \"o\", \"\\u001b[2J\"]")?;
+            self.cast = Some(cast);
+        }
+        let screen = format!("\x1b[H{}", render::ansi(frame).replace('\n', "\r\n"));
+        let time = self.frames as f64 * FRAME_TIME;
+        if let Some(cast) = self.cast.as_mut() {
+            writeln!(cast, "[{:.3}, \"o\", {}]", time, bench::json_string(&screen))?;
+        }
+        self.frames += 1;
+        Ok(())
+    }
+
+    // The number of frames written.
+    pub fn finish(mut self) -> io::Result<usize> {
+        if let Some(frame) = self.skipped.take() {
+            if self.error.is_none() {
+                if let Err(e) = self.write(&frame) {self.error = Some(e);}
+            }
+        }
+        if let Some(e) = self.error.take() {return Err(e);}
+        if let Some(cast) = self.cast.as_mut() {cast.flush()?;}
+        Ok(self.frames)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{render::Tile, Grid};
+
+    fn frame(c: char) -> Frame {
+        Grid::new(2, 2, Tile::new(c, render::WHITE))
+    }
+
+    #[test]
+    fn record() {
+        let dir = std::env::temp_dir().join(format!("aoc-animate-{}", std::process::id()));
+        let mut recorder = Recorder::new(&dir, 2, FrameFormat::Text).unwrap();
+        for c in ['a', 'b', 'c', 'd'] {
+            recorder.record(&frame(c));
+        }
+        assert_eq!(recorder.finish().unwrap(), 3);
+        assert_eq!(fs::read_to_string(dir.join("frame_00001.txt")).unwrap(), "cc\ncc\n");
+        assert_eq!(fs::read_to_string(dir.join("frame_00002.txt")).unwrap(), "dd\ndd\n");
+        let cast = fs::read_to_string(dir.join("animation.cast")).unwrap();
+        let lines: Vec<&str> = cast.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[0], "{\"version\": 2, \"width\": 2, \"height\": 2}");
+        assert!(lines[4].starts_with("[0.200, \"o\", \"\\u001b[H\\u001b[38;2;255;255;255mdd"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
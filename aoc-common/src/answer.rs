@@ -0,0 +1,98 @@
+use std::fmt;
+
+// Integers that fit in an i64 are always stored as Int, so two answers with
+// the same value compare equal no matter which type the day produced.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Int(i64),
+    BigInt(i128),
+    Str(String)
+}
+
+impl Answer {
+    pub fn parse(s: &str) -> Self {
+        let s = s.trim();
+        if let Ok(x) = s.parse::<i128>() {
+            return Self::from(x);
+        }
+        Answer::Str(s.to_string())
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Int(x) => write!(f, "{}", x),
+            Answer::BigInt(x) => write!(f, "{}", x),
+            Answer::Str(s) => write!(f, "{}", s)
+        }
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(x: i128) -> Self {
+        match i64::try_from(x) {
+            Ok(x) => Answer::Int(x),
+            Err(_) => Answer::BigInt(x)
+        }
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(x: u128) -> Self {
+        match i128::try_from(x) {
+            Ok(x) => Self::from(x),
+            Err(_) => Answer::Str(x.to_string())
+        }
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(x: $t) -> Self {
+                Self::from(x as i128)
+            }
+        })*
+    };
+}
+
+from_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_string())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn from_ints() {
+        assert_eq!(Answer::from(41usize), Answer::Int(41));
+        assert_eq!(Answer::from(-3i32), Answer::Int(-3));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInt(u64::MAX as i128));
+        assert_eq!(Answer::from(u64::MAX as i128), Answer::from(u64::MAX));
+    }
+
+    #[test]
+    fn parse_() {
+        assert_eq!(Answer::parse("6398096697992"), Answer::Int(6398096697992));
+        assert_eq!(Answer::parse(" 18446744073709551616\n"), Answer::BigInt(18446744073709551616));
+        assert_eq!(Answer::parse("7,1,3,7,5,1,0,3,4"), Answer::Str("7,1,3,7,5,1,0,3,4".to_string()));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::from(2858u64).to_string(), "2858");
+        assert_eq!(Answer::from("4,6,3").to_string(), "4,6,3");
+    }
+}
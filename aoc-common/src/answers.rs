@@ -0,0 +1,174 @@
+use std::{collections::HashMap, fmt};
+use crate::{Answer, ParseError};
+
+// Known puzzle answers, read from a small subset of TOML:
+//
+//     [day9]
+//     part1 = 6370402949053
+//     part2 = "6398096697992"   # strings work too
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers {
+    days: HashMap<u8, [Option<Answer>; 2]>
+}
+
+impl Answers {
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        Self::parse_from(s, None)
+    }
+
+    // For files holding a single day's answers, such as the examples' .toml
+    // files. These have no [dayN] header, and one is an error.
+    pub fn parse_day(day: u8, s: &str) -> Result<Self, ParseError> {
+        Self::parse_from(s, Some(day))
+    }
+
+    fn parse_from(s: &str, day: Option<u8>) -> Result<Self, ParseError> {
+        let mut answers = Answers::default();
+        let single = day.is_some();
+        let mut day = day;
+        for (i, raw) in s.lines().enumerate() {
+            let indent = raw.len() - raw.trim_start().len();
+            let line = raw.trim();
+            if line.is_empty() || line.starts_with('#') {continue;}
+            let col = |pos: usize| raw[..indent + pos].chars().count() + 1;
+
+            if let Some(header) = line.strip_prefix('[') {
+                if single {
+                    return Err(ParseError::invalid(i + 1, col(0), "a single day's answers take no [dayN] table"));
+                }
+                let end = header.find(']').ok_or_else(|| ParseError::eof(i + 1, col(line.len()), "']'"))?;
+                expect_comment(&header[end+1..], i + 1, col(end + 2))?;
+                day = match header[..end].trim().strip_prefix("day").and_then(|n| n.parse::<u8>().ok()) {
+                    Some(n) => Some(n),
+                    None => {return Err(ParseError::invalid(i + 1, col(1), "expected a table like [day1]"));}
+                };
+                continue;
+            }
+
+            let (key, value) = line.split_once('=').ok_or_else(|| ParseError::invalid(i + 1, col(0), "expected key = value"))?;
+            let part = match key.trim() {
+                "part1" => 0,
+                "part2" => 1,
+                _ => {return Err(ParseError::invalid(i + 1, col(0), "expected part1 or part2"));}
+            };
+            let day = day.ok_or_else(|| ParseError::invalid(i + 1, col(0), "answer outside of a [dayN] table"))?;
+            let value_at = key.len() + 1 + (value.len() - value.trim_start().len());
+            let answer = parse_value(value.trim(), i + 1, col(value_at))?;
+            let slot = &mut answers.days.entry(day).or_insert([None, None])[part];
+            if slot.is_some() {
+                return Err(ParseError::invalid(i + 1, col(0), "duplicate answer"));
+            }
+            *slot = Some(answer);
+        }
+        Ok(answers)
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        self.days.get(&day)?.get(part as usize - 1)?.as_ref()
+    }
+
+    // Answers are compared by how they print, so "42" in the file matches a
+    // day that returns the number 42.
+    pub fn check(&self, day: u8, part: u8, got: &Answer) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Unknown,
+            Some(expected) if expected.to_string() == got.to_string() => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected.clone())
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Verdict {
+    Pass,
+    Fail(Answer),
+    Unknown
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail(expected) => write!(f, "FAIL, expected {}", expected),
+            Verdict::Unknown => write!(f, "unknown")
+        }
+    }
+}
+
+fn parse_value(value: &str, line: usize, column: usize) -> Result<Answer, ParseError> {
+    if let Some(quoted) = value.strip_prefix('"') {
+        let end = quoted.find('"').ok_or_else(|| ParseError::eof(line, column + value.chars().count(), "'\"'"))?;
+        expect_comment(&quoted[end+1..], line, column + quoted[..end].chars().count() + 2)?;
+        return Ok(Answer::parse(&quoted[..end]));
+    }
+    let end = value.find('#').unwrap_or(value.len());
+    let digits = value[..end].trim().replace('_', "");
+    match digits.parse::<i128>() {
+        Ok(x) => Ok(Answer::from(x)),
+        Err(_) => match value.chars().next() {
+            Some(c) => Err(ParseError::unexpected(line, column, "an integer or a string", c)),
+            None => Err(ParseError::eof(line, column, "an integer or a string"))
+        }
+    }
+}
+
+fn expect_comment(rest: &str, line: usize, column: usize) -> Result<(), ParseError> {
+    let trimmed = rest.trim_start();
+    match trimmed.chars().next() {
+        None | Some('#') => Ok(()),
+        Some(c) => Err(ParseError::unexpected(line, column + rest.len() - trimmed.len(), "end of line", c))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_() {
+        let answers = Answers::parse("# known answers
+[day1]
+part1 = 2742123
+part2 = 21_328_497  # underscores are fine
+
+[day17]
+part1 = \"7,1,3,7,5,1,0,3,4\"
+").unwrap();
+        assert_eq!(answers.get(1, 1), Some(&Answer::Int(2742123)));
+        assert_eq!(answers.get(1, 2), Some(&Answer::Int(21328497)));
+        assert_eq!(answers.get(17, 1), Some(&Answer::Str("7,1,3,7,5,1,0,3,4".to_string())));
+        assert_eq!(answers.get(17, 2), None);
+        assert_eq!(answers.get(2, 1), None);
+    }
+
+    #[test]
+    fn parse_day() {
+        let answers = Answers::parse_day(6, "part2 = 6\n").unwrap();
+        assert_eq!(answers.get(6, 1), None);
+        assert_eq!(answers.get(6, 2), Some(&Answer::Int(6)));
+        let e = Answers::parse_day(6, "part1 = 4\n[day6]\npart2 = 6\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        assert!(Answers::parse_day(6, "[day7]\npart1 = 4\n").is_err());
+    }
+
+    #[test]
+    fn check() {
+        let answers = Answers::parse("[day17]\npart1 = \"4,6\"\n[day3]\npart2 = \"42\"\n").unwrap();
+        assert_eq!(answers.check(17, 1, &Answer::from("4,6")), Verdict::Pass);
+        assert_eq!(answers.check(17, 1, &Answer::from("4,7")), Verdict::Fail(Answer::from("4,6")));
+        assert_eq!(answers.check(17, 2, &Answer::from(3)), Verdict::Unknown);
+        assert_eq!(answers.check(3, 2, &Answer::from(42u64)), Verdict::Pass);
+    }
+
+    #[test]
+    fn parse_err() {
+        let e = Answers::parse("part1 = 4\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+        let e = Answers::parse("[day1]\npart1 = x4\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.found()), (2, 9, Some('x')));
+        let e = Answers::parse("[day1]\npart1 = \"4\" 5\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.found()), (2, 13, Some('5')));
+        assert!(Answers::parse("[one]\n").is_err());
+        assert!(Answers::parse("[day1]\npart1 = 1\npart1 = 2\n").is_err());
+    }
+}
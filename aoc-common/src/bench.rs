@@ -0,0 +1,160 @@
+use std::{fmt::Write, time::Duration};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    // None for a part that was skipped.
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>
+}
+
+impl Timings {
+    pub fn stages(&self) -> [(&'static str, Option<Duration>); 3] {
+        [("parse", Some(self.parse)), ("part1", self.part1), ("part2", self.part2)]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to summarize");
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let mid = sorted.len() / 2;
+        let median = match sorted.len() % 2 {
+            0 => (sorted[mid-1] + sorted[mid]) / 2,
+            _ => sorted[mid]
+        };
+        Self {
+            min: sorted[0],
+            median,
+            max: sorted[sorted.len()-1]
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub day: u8,
+    pub input: String,
+    pub runs: usize,
+    // None for a stage that was skipped.
+    pub stages: [(&'static str, Option<Stats>); 3]
+}
+
+impl Report {
+    pub fn from_timings(day: u8, input: &str, timings: &[Timings]) -> Self {
+        let stats = |i: usize| {
+            let samples: Option<Vec<Duration>> = timings.iter().map(|t| t.stages()[i].1).collect();
+            samples.map(|samples| Stats::from_samples(&samples))
+        };
+        Self {
+            day,
+            input: input.to_string(),
+            runs: timings.len(),
+            stages: [("parse", stats(0)), ("part1", stats(1)), ("part2", stats(2))]
+        }
+    }
+}
+
+pub fn table(reports: &[Report]) -> String {
+    let width = reports.iter().map(|r| r.input.chars().count()).max().unwrap_or(0).max(5);
+    let mut out = format!("{:>3}  {:<width$}  {:<5}  {:>10}  {:>10}  {:>10}\n", "day", "input", "stage", "min", "median", "max");
+    for report in reports.iter() {
+        for (stage, stats) in report.stages.iter() {
+            let [min, median, max] = match stats {
+                Some(stats) => [stats.min, stats.median, stats.max].map(format_duration),
+                None => ["-", "-", "-"].map(String::from)
+            };
+            let _ = writeln!(out, "{:>3}  {:<width$}  {:<5}  {:>10}  {:>10}  {:>10}", report.day, report.input, stage, min, median, max);
+        }
+    }
+    out
+}
+
+pub fn json(reports: &[Report]) -> String {
+    let days: Vec<String> = reports.iter().map(|report| {
+        let stages: Vec<String> = report.stages.iter().map(|(stage, stats)| match stats {
+            Some(stats) => format!("\"{}\":{{\"min_ns\":{},\"median_ns\":{},\"max_ns\":{}}}", stage,
+                stats.min.as_nanos(), stats.median.as_nanos(), stats.max.as_nanos()),
+            None => format!("\"{}\":null", stage)
+        }).collect();
+        format!("{{\"day\":{},\"input\":{},\"runs\":{},{}}}", report.day, json_string(&report.input), report.runs, stages.join(","))
+    }).collect();
+    format!("[{}]\n", days.join(","))
+}
+
+pub(crate) fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => {let _ = write!(out, "\\u{:04x}", c as u32);},
+            c => out.push(c)
+        }
+    }
+    out.push('"');
+    out
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let ns = d.as_nanos();
+    if ns < 1_000 {format!("{}ns", ns)}
+    else if ns < 1_000_000 {format!("{:.1}µs", ns as f64 / 1e3)}
+    else if ns < 1_000_000_000 {format!("{:.1}ms", ns as f64 / 1e6)}
+    else {format!("{:.2}s", ns as f64 / 1e9)}
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn ms(x: u64) -> Duration {
+        Duration::from_millis(x)
+    }
+
+    #[test]
+    fn from_samples() {
+        assert_eq!(Stats::from_samples(&[ms(5), ms(1), ms(3)]), Stats { min: ms(1), median: ms(3), max: ms(5) });
+        assert_eq!(Stats::from_samples(&[ms(4), ms(1), ms(2), ms(9)]).median, ms(3));
+    }
+
+    #[test]
+    fn json_() {
+        let timings = [Timings { parse: ms(1), part1: Some(ms(2)), part2: Some(ms(3)) }];
+        let report = Report::from_timings(6, "input.txt", &timings);
+        assert_eq!(json(&[report]), "[{\"day\":6,\"input\":\"input.txt\",\"runs\":1,\
+\"parse\":{\"min_ns\":1000000,\"median_ns\":1000000,\"max_ns\":1000000},\
+\"part1\":{\"min_ns\":2000000,\"median_ns\":2000000,\"max_ns\":2000000},\
+\"part2\":{\"min_ns\":3000000,\"median_ns\":3000000,\"max_ns\":3000000}}]\n");
+    }
+
+    #[test]
+    fn skipped() {
+        let timings = [Timings { parse: ms(1), part1: None, part2: Some(ms(3)) }, Timings { parse: ms(2), part1: None, part2: Some(ms(4)) }];
+        let report = Report::from_timings(6, "in", &timings);
+        assert_eq!(report.stages[1], ("part1", None));
+        assert!(json(std::slice::from_ref(&report)).contains("\"part1\":null,"));
+        let row = table(&[report]).lines().nth(2).unwrap().to_string();
+        assert_eq!(row.split_whitespace().collect::<Vec<_>>(), vec!["6", "in", "part1", "-", "-", "-"]);
+    }
+
+    #[test]
+    fn json_string_() {
+        assert_eq!(json_string("C:\\in \"1\".txt\n"), "\"C:\\\\in \\\"1\\\".txt\\u000a\"");
+    }
+
+    #[test]
+    fn format_duration_() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.5ms");
+        assert_eq!(format_duration(Duration::from_secs(75)), "75.00s");
+    }
+}
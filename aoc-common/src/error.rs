@@ -0,0 +1,92 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseErrorKind {
+    Unexpected { expected: String, found: char },
+    Eof { expected: String },
+    BadNumber { text: String },
+    Invalid { reason: String }
+}
+
+// Lines and columns are counted from 1, columns in chars.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ParseErrorKind
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, kind: ParseErrorKind) -> Self {
+        Self {
+            line,
+            column,
+            kind
+        }
+    }
+
+    pub fn at(s: &str, pos: usize, kind: ParseErrorKind) -> Self {
+        let pos = pos.min(s.len());
+        let line_start = s[..pos].rfind('\n').map_or(0, |i| i + 1);
+        let line = s[..line_start].matches('\n').count() + 1;
+        let column = s[line_start..pos].chars().count() + 1;
+        Self::new(line, column, kind)
+    }
+
+    pub fn unexpected(line: usize, column: usize, expected: &str, found: char) -> Self {
+        Self::new(line, column, ParseErrorKind::Unexpected { expected: expected.to_string(), found })
+    }
+
+    pub fn eof(line: usize, column: usize, expected: &str) -> Self {
+        Self::new(line, column, ParseErrorKind::Eof { expected: expected.to_string() })
+    }
+
+    pub fn invalid(line: usize, column: usize, reason: &str) -> Self {
+        Self::new(line, column, ParseErrorKind::Invalid { reason: reason.to_string() })
+    }
+
+    pub fn found(&self) -> Option<char> {
+        match self.kind {
+            ParseErrorKind::Unexpected { found, .. } => Some(found),
+            _ => None
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match &self.kind {
+            ParseErrorKind::Unexpected { expected, found } => write!(f, "expected {}, found {:?}", expected, found),
+            ParseErrorKind::Eof { expected } => write!(f, "expected {}, found end of input", expected),
+            ParseErrorKind::BadNumber { text } => write!(f, "number '{}' does not fit", text),
+            ParseErrorKind::Invalid { reason } => write!(f, "{}", reason)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn at_() {
+        let s = "ab\ncd\r\nef";
+        assert_eq!(ParseError::at(s, 0, ParseErrorKind::Invalid { reason: String::new() }).column, 1);
+        let e = ParseError::at(s, 4, ParseErrorKind::Invalid { reason: String::new() });
+        assert_eq!((e.line, e.column), (2, 2));
+        let e = ParseError::at(s, 7, ParseErrorKind::Invalid { reason: String::new() });
+        assert_eq!((e.line, e.column), (3, 1));
+        let e = ParseError::at(s, 100, ParseErrorKind::Invalid { reason: String::new() });
+        assert_eq!((e.line, e.column), (3, 3));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(ParseError::unexpected(3, 7, "'#' or '.'", 'x').to_string(), "line 3, column 7: expected '#' or '.', found 'x'");
+        assert_eq!(ParseError::eof(1, 4, "a digit").to_string(), "line 1, column 4: expected a digit, found end of input");
+        assert_eq!(ParseError::invalid(2, 1, "guard not found").to_string(), "line 2, column 1: guard not found");
+    }
+}
@@ -0,0 +1,217 @@
+use std::ops::{Index, IndexMut};
+use crate::{Direction, ParseError};
+
+// (row, column), both counted from 0.
+pub type Pos = (usize, usize);
+
+// Row and column steps, clockwise from up.
+pub const NEIGHBORS4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+pub const NEIGHBORS8: [(isize, isize); 8] = [(-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1), (-1, -1)];
+
+// A dense rectangle of cells stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, cols: usize, fill: T) -> Self
+    where T: Clone {
+        Self {
+            rows,
+            cols,
+            cells: vec![fill; rows * cols]
+        }
+    }
+
+    // Reads one row per line, skipping blank lines. `cell` turns each char into
+    // a cell, or returns None for a char that does not belong, in which case
+    // the error says `expected` instead.
+    pub fn parse<F>(s: &str, expected: &str, mut cell: F) -> Result<Self, ParseError>
+    where F: FnMut(Pos, char) -> Option<T> {
+        let mut rows = 0;
+        let mut cols: Option<usize> = None;
+        let mut cells = Vec::new();
+        for (line, row) in s.lines().enumerate() {
+            let row = row.trim_end();
+            if row.is_empty() {continue;}
+            let mut width = 0;
+            for (col, c) in row.chars().enumerate() {
+                if cols.is_some_and(|w| col >= w) {
+                    return Err(ParseError::invalid(line + 1, col + 1, "inconsistent width while scanning map"));
+                }
+                let value = cell((rows, col), c).ok_or_else(|| ParseError::unexpected(line + 1, col + 1, expected, c))?;
+                cells.push(value);
+                width += 1;
+            }
+            match cols {
+                None => {cols = Some(width);},
+                Some(w) if w != width => {return Err(ParseError::invalid(line + 1, width + 1, "inconsistent width while scanning map"));},
+                _ => {}
+            }
+            rows += 1;
+        }
+        let cols = cols.ok_or_else(|| ParseError::eof(s.lines().count() + 1, 1, expected))?;
+        Ok(Self {
+            rows,
+            cols,
+            cells
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.0 < self.rows && pos.1 < self.cols
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if !self.contains(pos) {return None;}
+        self.cells.get(pos.0 * self.cols + pos.1)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if !self.contains(pos) {return None;}
+        self.cells.get_mut(pos.0 * self.cols + pos.1)
+    }
+
+    // The position one `step` away, if that is still on the grid.
+    pub fn offset(&self, pos: Pos, step: (isize, isize)) -> Option<Pos> {
+        let next = (pos.0.checked_add_signed(step.0)?, pos.1.checked_add_signed(step.1)?);
+        if self.contains(next) {Some(next)} else {None}
+    }
+
+    pub fn step(&self, pos: Pos, dir: Direction) -> Option<Pos> {
+        let delta = dir.delta();
+        self.offset(pos, (delta.y as isize, delta.x as isize))
+    }
+
+    pub fn neighbors4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBORS4.into_iter().filter_map(move |step| self.offset(pos, step))
+    }
+
+    pub fn neighbors8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        NEIGHBORS8.into_iter().filter_map(move |step| self.offset(pos, step))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let cols = self.cols;
+        (0..self.rows * cols).map(move |i| (i / cols, i % cols))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            cells: self.cells.iter().map(f).collect()
+        }
+    }
+
+    // Checks every cell on the edge is a wall, for maps whose walkers never
+    // expect to step off. `s` is the text the grid was parsed from, used to
+    // point at the first open cell.
+    pub fn walled<F: Fn(&T) -> bool>(&self, s: &str, wall: F) -> Result<(), ParseError> {
+        let edge = |&(i, j): &Pos| i == 0 || j == 0 || i + 1 == self.rows || j + 1 == self.cols;
+        let Some((i, j)) = self.positions().filter(edge).find(|&pos| !wall(&self[pos])) else {return Ok(());};
+        let line = s.lines().enumerate().filter(|(_, l)| !l.trim_end().is_empty()).nth(i).map_or(i, |(n, _)| n);
+        Err(ParseError::invalid(line + 1, j + 1, "the edge of the map must be wall"))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(cell) => cell,
+            None => {panic!("{:?} is outside a {}x{} grid", pos, self.rows, self.cols);}
+        }
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        match self.get_mut(pos) {
+            Some(cell) => cell,
+            None => {panic!("{:?} is outside a {}x{} grid", pos, rows, cols);}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn digits(s: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(s, "a digit", |_, c| c.to_digit(10))
+    }
+
+    #[test]
+    fn parse() {
+        let grid = digits("123\r\n456\n\n").unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.get((1, 3)), None);
+        assert_eq!(grid.get((2, 0)), None);
+    }
+
+    #[test]
+    fn parse_err() {
+        let e = digits("123\n4x6\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.found()), (2, 2, Some('x')));
+        let e = digits("123\n45\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+        let e = digits("123\n4567\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 4));
+        assert!(digits("\n").is_err());
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = Grid::new(3, 4, 0);
+        assert_eq!(grid.neighbors4((0, 0)).collect::<Vec<_>>(), vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8((0, 3)).collect::<Vec<_>>(), vec![(1, 3), (1, 2), (0, 2)]);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn offset() {
+        let grid = Grid::new(3, 4, 0);
+        assert_eq!(grid.offset((1, 1), (1, 2)), Some((2, 3)));
+        assert_eq!(grid.offset((1, 1), (-2, 0)), None);
+        assert_eq!(grid.offset((2, 3), (0, 1)), None);
+        assert_eq!(grid.step((1, 1), Direction::Left), Some((1, 0)));
+        assert_eq!(grid.step((0, 1), Direction::Up), None);
+    }
+
+    #[test]
+    fn iter() {
+        let mut grid = digits("12\n34\n").unwrap();
+        grid[(0, 1)] = 7;
+        let cells: Vec<(Pos, u32)> = grid.iter().map(|(pos, &x)| (pos, x)).collect();
+        assert_eq!(cells, vec![((0, 0), 1), ((0, 1), 7), ((1, 0), 3), ((1, 1), 4)]);
+        assert_eq!(grid.map(|&x| x * 2)[(1, 1)], 8);
+    }
+
+    #[test]
+    fn walled() {
+        assert_eq!(digits("111\n101\n111\n").unwrap().walled("", |&x| x == 1), Ok(()));
+        let s = "\n111\n\n101\n110\n";
+        let e = digits(s).unwrap().walled(s, |&x| x == 1).unwrap_err();
+        assert_eq!((e.line, e.column), (5, 3));
+    }
+}
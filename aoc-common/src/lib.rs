@@ -0,0 +1,19 @@
+pub mod animate;
+pub mod answer;
+pub mod answers;
+pub mod bench;
+pub mod error;
+pub mod geometry;
+pub mod grid;
+pub mod render;
+pub mod runner;
+pub mod scanner;
+pub mod solution;
+
+pub use answer::Answer;
+pub use answers::{Answers, Verdict};
+pub use error::{ParseError, ParseErrorKind};
+pub use geometry::{Direction, Point};
+pub use grid::Grid;
+pub use scanner::{Scanner, ScanError};
+pub use solution::Solution;
@@ -0,0 +1,224 @@
+use std::{fmt::Write as _, fs, io, path::Path};
+use crate::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+pub const BACKGROUND: Rgb = Rgb(0, 0, 0);
+pub const DIM: Rgb = Rgb(60, 60, 60);
+pub const WALL: Rgb = Rgb(150, 150, 150);
+pub const WHITE: Rgb = Rgb(255, 255, 255);
+pub const RED: Rgb = Rgb(230, 60, 50);
+pub const GREEN: Rgb = Rgb(70, 200, 80);
+pub const YELLOW: Rgb = Rgb(240, 200, 50);
+pub const ORANGE: Rgb = Rgb(240, 140, 40);
+pub const BLUE: Rgb = Rgb(70, 130, 230);
+
+// Pixels per tile side in exported images.
+pub const DEFAULT_SCALE: usize = 4;
+
+// One square of a picture: the char shown in a terminal and the color used
+// for it there and in images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tile {
+    pub glyph: char,
+    pub color: Rgb
+}
+
+impl Tile {
+    pub fn new(glyph: char, color: Rgb) -> Self {
+        Self {
+            glyph,
+            color
+        }
+    }
+}
+
+impl Default for Tile {
+    fn default() -> Self {
+        Tile::new(' ', BACKGROUND)
+    }
+}
+
+pub type Frame = Grid<Tile>;
+
+pub fn text(frame: &Frame) -> String {
+    let mut out = String::with_capacity(frame.rows() * (frame.cols() + 1));
+    for row in 0..frame.rows() {
+        for col in 0..frame.cols() {
+            out.push(frame[(row, col)].glyph);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+// 24-bit color escapes, switching color only where it changes.
+pub fn ansi(frame: &Frame) -> String {
+    let mut out = String::new();
+    for row in 0..frame.rows() {
+        let mut current: Option<Rgb> = None;
+        for col in 0..frame.cols() {
+            let tile = frame[(row, col)];
+            if current != Some(tile.color) {
+                let Rgb(r, g, b) = tile.color;
+                let _ = write!(out, "\x1b[38;2;{};{};{}m", r, g, b);
+                current = Some(tile.color);
+            }
+            out.push(tile.glyph);
+        }
+        out.push_str("\x1b[0m\n");
+    }
+    out
+}
+
+fn pixels(frame: &Frame, scale: usize) -> (usize, usize, Vec<u8>) {
+    let (width, height) = (frame.cols() * scale, frame.rows() * scale);
+    let mut data = Vec::with_capacity(width * height * 3);
+    for y in 0..height {
+        for x in 0..width {
+            let Rgb(r, g, b) = frame[(y / scale, x / scale)].color;
+            data.extend([r, g, b]);
+        }
+    }
+    (width, height, data)
+}
+
+pub fn ppm(frame: &Frame, scale: usize) -> Vec<u8> {
+    let (width, height, data) = pixels(frame, scale);
+    let mut out = format!("P6\n{} {}\n255\n", width, height).into_bytes();
+    out.extend(data);
+    out
+}
+
+// An 8-bit RGB PNG. The image data is stored without compression, which
+// keeps the encoder short at the cost of bigger files.
+pub fn png(frame: &Frame, scale: usize) -> Vec<u8> {
+    let (width, height, data) = pixels(frame, scale);
+    let mut raw = Vec::with_capacity(height * (width * 3 + 1));
+    for line in data.chunks(width * 3).take(height) {
+        raw.push(0);
+        raw.extend_from_slice(line);
+    }
+
+    let mut header = Vec::with_capacity(13);
+    header.extend((width as u32).to_be_bytes());
+    header.extend((height as u32).to_be_bytes());
+    header.extend([8, 2, 0, 0, 0]);
+
+    let mut out = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+    png_chunk(&mut out, b"IHDR", &header);
+    png_chunk(&mut out, b"IDAT", &zlib_stored(&raw));
+    png_chunk(&mut out, b"IEND", &[]);
+    out
+}
+
+fn png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], body: &[u8]) {
+    out.extend((body.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend_from_slice(kind);
+    out.extend_from_slice(body);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(0xffff).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        out.push(blocks.peek().is_none() as u8);
+        let len = block.len() as u16;
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {(crc >> 1) ^ 0xedb8_8320} else {crc >> 1};
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+// Picks the format from the extension: .png, .ppm, or plain text otherwise.
+pub fn save(frame: &Frame, path: &Path, scale: usize) -> io::Result<()> {
+    let bytes = match path.extension().and_then(|e| e.to_str()) {
+        Some("png") => png(frame, scale),
+        Some("ppm") => ppm(frame, scale),
+        _ => text(frame).into_bytes()
+    };
+    fs::write(path, bytes)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn frame() -> Frame {
+        let mut frame = Grid::new(2, 3, Tile::new('.', DIM));
+        frame[(0, 1)] = Tile::new('#', WALL);
+        frame[(1, 2)] = Tile::new('@', RED);
+        frame
+    }
+
+    #[test]
+    fn text_() {
+        assert_eq!(text(&frame()), ".#.\n..@\n");
+    }
+
+    #[test]
+    fn ansi_() {
+        let out = ansi(&frame());
+        assert!(out.starts_with("\x1b[38;2;60;60;60m.\x1b[38;2;150;150;150m#\x1b[38;2;60;60;60m.\x1b[0m\n"));
+        assert_eq!(out.matches("\x1b[0m\n").count(), 2);
+    }
+
+    #[test]
+    fn ppm_() {
+        let out = ppm(&frame(), 2);
+        assert!(out.starts_with(b"P6\n6 4\n255\n"));
+        assert_eq!(out.len(), 11 + 6 * 4 * 3);
+        let header = 11;
+        assert_eq!(&out[header + 2 * 3..header + 3 * 3], &[150, 150, 150]);
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn png_() {
+        let out = png(&frame(), 1);
+        assert_eq!(&out[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&out[12..16], b"IHDR");
+        assert_eq!(&out[16..24], &[0, 0, 0, 3, 0, 0, 0, 2]);
+        assert!(out.ends_with(&[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]));
+        let idat_len = u32::from_be_bytes(out[33..37].try_into().unwrap()) as usize;
+        assert_eq!(&out[37..41], b"IDAT");
+        // zlib header, one final stored block of 2 rows * (1 + 3 * 3) bytes, adler32
+        assert_eq!(idat_len, 2 + 5 + 20 + 4);
+        assert_eq!(&out[41..48], &[0x78, 0x01, 1, 20, 0, !20, 0xff]);
+    }
+}
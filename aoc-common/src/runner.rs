@@ -0,0 +1,586 @@
+use std::{any::Any, env, fmt, fs::{self, File}, io::{self, Read}, panic::{self, AssertUnwindSafe}, path::{Path, PathBuf}, process::exit, sync::{mpsc, Arc}, thread, time::{Duration, Instant}};
+use crate::{animate::{FrameFormat, Recorder}, bench::{self, Report, Timings}, render, Answer, ParseError, Solution};
+
+pub type SolveFn = fn(&str, &RunOptions) -> Result<([Outcome; 2], Timings), ParseError>;
+
+const USAGE: &str = "Usage: cargo run -- [FILE | - ...] [--part 1|2|both] [--timeout SECS] [--bench N] [--json FILE]
+       [--render FILE | -] [--animate DIR [--every N] [--frames txt|ppm|png]]
+A part that times out cannot be stopped and keeps using a CPU until the program exits,
+so the timings of anything run after it may be slower.";
+
+// The flags above that are followed by a value.
+const VALUE_FLAGS: [&str; 8] = ["--part", "--timeout", "--bench", "--json", "--render", "--animate", "--every", "--frames"];
+
+// Flags shared by the day binaries and the aoc runner.
+#[derive(Debug, PartialEq, Default)]
+pub struct RunOptions {
+    pub bench: Option<usize>,
+    pub json: Option<PathBuf>,
+    // None runs both parts.
+    pub part: Option<u8>,
+    pub timeout: Option<Duration>
+}
+
+impl RunOptions {
+    // Consumes `flag` and its value if it is one of ours.
+    pub fn take_flag<'a>(&mut self, flag: &str, args: &mut impl Iterator<Item = &'a str>) -> Result<bool, String> {
+        match flag {
+            "--bench" => {
+                let n = args.next().ok_or("--bench needs a run count")?;
+                match n.parse::<usize>() {
+                    Ok(n) if n > 0 => {self.bench = Some(n);},
+                    _ => {return Err(format!("expected a positive run count, found '{}'", n));}
+                }
+            },
+            "--json" => {
+                let path = args.next().ok_or("--json needs a file")?;
+                self.json = Some(PathBuf::from(path));
+            },
+            "--part" => {
+                self.part = match args.next().ok_or("--part needs 1, 2 or both")? {
+                    "1" => Some(1),
+                    "2" => Some(2),
+                    "both" => None,
+                    other => {return Err(format!("expected 1, 2 or both, found '{}'", other));}
+                };
+            },
+            "--timeout" => {
+                let secs = args.next().ok_or("--timeout needs a number of seconds")?;
+                match secs.parse::<f64>() {
+                    Ok(s) if s > 0.0 && s.is_finite() => {self.timeout = Some(Duration::from_secs_f64(s));},
+                    _ => {return Err(format!("expected a positive number of seconds, found '{}'", secs));}
+                }
+            },
+            _ => {return Ok(false);}
+        }
+        Ok(true)
+    }
+
+    pub fn runs(&self) -> usize {
+        self.bench.unwrap_or(1)
+    }
+
+    pub fn wants(&self, part: u8) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Solved(Answer),
+    Skipped,
+    TimedOut,
+    // The part returned an error or panicked.
+    Failed(String)
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Solved(answer) => write!(f, "{}", answer),
+            Outcome::Skipped => write!(f, "skipped"),
+            Outcome::TimedOut => write!(f, "timed out"),
+            Outcome::Failed(reason) => write!(f, "failed: {}", reason)
+        }
+    }
+}
+
+pub fn read_input(path: &Path) -> io::Result<String> {
+    let mut input = String::new();
+    File::open(path)?.read_to_string(&mut input)?;
+    Ok(input)
+}
+
+// "-" reads standard input.
+pub fn read_source(source: &str) -> io::Result<String> {
+    if source != "-" {
+        return read_input(Path::new(source));
+    }
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+    Ok(input)
+}
+
+// Looks for `relative` from the current directory and then each directory
+// above it, so the binaries work from anywhere in the workspace wherever
+// they were built.
+pub fn find_in_workspace(relative: &Path) -> Option<PathBuf> {
+    let dir = env::current_dir().ok()?;
+    dir.ancestors().map(|d| d.join(relative)).find(|path| path.is_file())
+}
+
+pub fn default_input(day: u8) -> Option<PathBuf> {
+    find_in_workspace(&Path::new(&format!("AoC_12_{}", day)).join("input.txt"))
+}
+
+// The day's input file, or "-" for standard input when there is none.
+pub fn default_source(day: u8) -> String {
+    default_input(day).map_or("-".to_string(), |path| path.display().to_string())
+}
+
+pub fn solve<S: Solution + 'static>(input: &str, options: &RunOptions) -> Result<([Outcome; 2], Timings), ParseError>
+where S::Input: Send + Sync {
+    solve_with::<S>(&S::parse, input, options)
+}
+
+// For days whose parsing takes settings beyond the input text.
+pub fn solve_with<S: Solution + 'static>(parse: &dyn Fn(&str) -> Result<S::Input, ParseError>, input: &str, options: &RunOptions) -> Result<([Outcome; 2], Timings), ParseError>
+where S::Input: Send + Sync {
+    let start = Instant::now();
+    let parsed = Arc::new(parse(input)?);
+    let parse = start.elapsed();
+    let (part1, part1_time) = run_part(S::part1, &parsed, 1, options);
+    let (part2, part2_time) = run_part(S::part2, &parsed, 2, options);
+    Ok(([part1, part2], Timings { parse, part1: part1_time, part2: part2_time }))
+}
+
+// A part that runs past the timeout is left running on its thread; there is
+// no way to stop it, so the process just exits without waiting for it. Until
+// then it takes CPU time from whatever runs next.
+fn run_part<I: Send + Sync + 'static>(part_fn: fn(&I) -> Result<Answer, String>, input: &Arc<I>, part: u8, options: &RunOptions) -> (Outcome, Option<Duration>) {
+    if !options.wants(part) {
+        return (Outcome::Skipped, None);
+    }
+    let start = Instant::now();
+    let Some(timeout) = options.timeout else {
+        let outcome = outcome(panic::catch_unwind(AssertUnwindSafe(|| part_fn(input))));
+        return (outcome, Some(start.elapsed()));
+    };
+    let (tx, rx) = mpsc::channel();
+    let input = Arc::clone(input);
+    thread::spawn(move || {
+        let _ = tx.send(panic::catch_unwind(AssertUnwindSafe(|| part_fn(&input))));
+    });
+    match rx.recv_timeout(timeout) {
+        Ok(result) => (outcome(result), Some(start.elapsed())),
+        Err(mpsc::RecvTimeoutError::Timeout) => (Outcome::TimedOut, Some(timeout)),
+        Err(mpsc::RecvTimeoutError::Disconnected) => (Outcome::Failed(format!("part {} stopped without an answer", part)), Some(start.elapsed()))
+    }
+}
+
+fn outcome(result: thread::Result<Result<Answer, String>>) -> Outcome {
+    match result {
+        Ok(Ok(answer)) => Outcome::Solved(answer),
+        Ok(Err(reason)) => Outcome::Failed(reason),
+        Err(payload) => Outcome::Failed(format!("panicked: {}", panic_message(payload.as_ref())))
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(s), _) => s,
+        (_, Some(s)) => s,
+        _ => "no message"
+    }
+}
+
+// Solves `runs` times, keeping the answers from the first run. A timeout or
+// failure on the first run would only repeat, so that run is all we keep.
+pub fn measure(solve: impl Fn(&str, &RunOptions) -> Result<([Outcome; 2], Timings), ParseError>, input: &str, options: &RunOptions) -> Result<([Outcome; 2], Vec<Timings>), ParseError> {
+    let (outcomes, first) = solve(input, options)?;
+    let mut timings = vec![first];
+    if outcomes.iter().any(|o| matches!(o, Outcome::TimedOut | Outcome::Failed(_))) {
+        return Ok((outcomes, timings));
+    }
+    for _ in 1..options.runs() {
+        timings.push(solve(input, options)?.1);
+    }
+    Ok((outcomes, timings))
+}
+
+pub fn print_timings(report: &Report, outcomes: &[Outcome; 2]) {
+    let [parse, part1, part2] = report.stages.map(|(_, stats)| stats.map_or("-".to_string(), |s| bench::format_duration(s.median)));
+    let part = |outcome: &Outcome, time: String| match outcome {
+        Outcome::Solved(_) | Outcome::Failed(_) => time,
+        Outcome::Skipped => "skipped".to_string(),
+        Outcome::TimedOut => format!("over {}", time)
+    };
+    println!("Time: parse {}, part 1 {}, part 2 {}", parse, part(&outcomes[0], part1), part(&outcomes[1], part2));
+}
+
+pub fn finish(reports: &[Report], options: &RunOptions) -> io::Result<()> {
+    if options.bench.is_some() {
+        print!("{}", bench::table(reports));
+    }
+    if let Some(path) = &options.json {
+        fs::write(path, bench::json(reports))?;
+    }
+    Ok(())
+}
+
+// Picture flags, which only the day binaries take.
+#[derive(Debug, PartialEq, Default)]
+struct Visuals {
+    render: Option<String>,
+    animate: Option<PathBuf>,
+    every: Option<usize>,
+    frames: FrameFormat
+}
+
+impl Visuals {
+    fn take_flag<'a>(&mut self, flag: &str, args: &mut impl Iterator<Item = &'a str>) -> Result<bool, String> {
+        match flag {
+            "--render" => {
+                let target = args.next().ok_or("--render needs a file or -")?;
+                self.render = Some(target.to_string());
+            },
+            "--animate" => {
+                let dir = args.next().ok_or("--animate needs a directory")?;
+                self.animate = Some(PathBuf::from(dir));
+            },
+            "--every" => {
+                let n = args.next().ok_or("--every needs a step count")?;
+                match n.parse::<usize>() {
+                    Ok(n) if n > 0 => {self.every = Some(n);},
+                    _ => {return Err(format!("expected a positive step count, found '{}'", n));}
+                }
+            },
+            "--frames" => {
+                let format = args.next().ok_or("--frames needs txt, ppm or png")?;
+                self.frames = FrameFormat::parse(format).ok_or_else(|| format!("expected txt, ppm or png, found '{}'", format))?;
+            },
+            _ => {return Ok(false);}
+        }
+        Ok(true)
+    }
+
+    fn check(&self, inputs: usize) -> Result<(), String> {
+        if self.animate.is_none() && (self.every.is_some() || self.frames != FrameFormat::default()) {
+            return Err("--every and --frames go with --animate".to_string());
+        }
+        if (self.render.is_some() || self.animate.is_some()) && inputs > 1 {
+            return Err("--render and --animate take a single input".to_string());
+        }
+        Ok(())
+    }
+}
+
+// Draws each part that ran. "-" prints to the terminal; several parts going
+// to one file get the part number added to the name.
+fn render_parts<S: Solution>(parsed: &S::Input, target: &str, options: &RunOptions) -> Result<(), String> {
+    let parts: Vec<u8> = (1..=2).filter(|&part| options.wants(part)).collect();
+    for &part in parts.iter() {
+        let frame = S::render(parsed, part).ok_or_else(|| format!("day {} has nothing to render", S::DAY))?;
+        if target == "-" {
+            print!("{}", render::ansi(&frame));
+            continue;
+        }
+        let path = match parts.len() {
+            1 => PathBuf::from(target),
+            _ => part_path(Path::new(target), part)
+        };
+        render::save(&frame, &path, render::DEFAULT_SCALE).map_err(|e| format!("could not write '{}': {}", path.display(), e))?;
+    }
+    Ok(())
+}
+
+// Records each part that ran into `dir`, or into dir/part1 and dir/part2
+// when both did.
+fn animate_parts<S: Solution>(parsed: &S::Input, dir: &Path, visuals: &Visuals, options: &RunOptions) -> Result<(), String> {
+    let parts: Vec<u8> = (1..=2).filter(|&part| options.wants(part)).collect();
+    for &part in parts.iter() {
+        let dir = match parts.len() {
+            1 => dir.to_path_buf(),
+            _ => dir.join(format!("part{}", part))
+        };
+        let write_error = |e: io::Error| format!("could not write frames to '{}': {}", dir.display(), e);
+        let mut recorder = Recorder::new(&dir, visuals.every.unwrap_or(1), visuals.frames).map_err(write_error)?;
+        if !S::animate(parsed, part, &mut |frame| recorder.record(frame)) {
+            return Err(format!("day {} has nothing to animate", S::DAY));
+        }
+        let frames = recorder.finish().map_err(write_error)?;
+        println!("Part {}: {} frames in {}", part, frames, dir.display());
+    }
+    Ok(())
+}
+
+fn part_path(path: &Path, part: u8) -> PathBuf {
+    let stem = path.file_stem().map_or(String::new(), |s| s.to_string_lossy().into_owned());
+    let name = match path.extension() {
+        Some(ext) => format!("{}.part{}.{}", stem, part, ext.to_string_lossy()),
+        None => format!("{}.part{}", stem, part)
+    };
+    path.with_file_name(name)
+}
+
+pub fn day_main<S: Solution + 'static>()
+where S::Input: Send + Sync {
+    day_main_with_args::<S>(env::args().skip(1).collect());
+}
+
+// Our usage, followed by a day's own for days that take flags.
+pub fn usage(day_usage: &str) -> String {
+    match day_usage {
+        "" => USAGE.to_string(),
+        extra => format!("{}\n{}", USAGE, extra)
+    }
+}
+
+// Hands each argument to `take`, which consumes the day's own flags and their
+// values wherever they are. The rest come back for `day_main_with_args`, with
+// the values of our flags kept beside them so none is taken for a day flag.
+pub fn take_day_flags<F>(args: Vec<String>, mut take: F) -> Result<Vec<String>, String>
+where F: FnMut(&str, &mut dyn Iterator<Item = &str>) -> Result<bool, String> {
+    let mut rest: Vec<String> = vec![];
+    let mut arg_iter = args.iter().map(|a| a.as_str());
+    while let Some(arg) = arg_iter.next() {
+        if take(arg, &mut arg_iter)? {continue;}
+        rest.push(arg.to_string());
+        if VALUE_FLAGS.contains(&arg) {
+            rest.extend(arg_iter.next().map(|v| v.to_string()));
+        }
+    }
+    Ok(rest)
+}
+
+// For days that take flags of their own: they remove those and pass on the
+// rest.
+pub fn day_main_with_args<S: Solution + 'static>(args: Vec<String>)
+where S::Input: Send + Sync {
+    day_main_with_parser::<S>(args, "", &S::parse);
+}
+
+// For days whose flags change how the input is read: `parse` replaces
+// `S::parse` everywhere, and `day_usage` describes the day's flags after ours.
+pub fn day_main_with_parser<S: Solution + 'static>(args: Vec<String>, day_usage: &str, parse: &dyn Fn(&str) -> Result<S::Input, ParseError>)
+where S::Input: Send + Sync {
+    let usage = usage(day_usage);
+    let mut arg_iter = args.iter().map(|a| a.as_str());
+    let mut sources: Vec<String> = vec![];
+    let mut options = RunOptions::default();
+    let mut visuals = Visuals::default();
+    while let Some(arg) = arg_iter.next() {
+        if arg == "--help" || arg == "-h" {
+            println!("{}", usage);
+            exit(0);
+        }
+        match visuals.take_flag(arg, &mut arg_iter) {
+            Ok(true) => {continue;},
+            Ok(false) => {},
+            Err(e) => {
+                eprintln!("{}\n{}", e, usage);
+                exit(2);
+            }
+        }
+        match options.take_flag(arg, &mut arg_iter) {
+            Ok(true) => {},
+            Ok(false) if !arg.starts_with("--") => {sources.push(arg.to_string());},
+            Ok(false) => {
+                eprintln!("unknown argument '{}'\n{}", arg, usage);
+                exit(2);
+            },
+            Err(e) => {
+                eprintln!("{}\n{}", e, usage);
+                exit(2);
+            }
+        }
+    }
+    if sources.is_empty() {
+        sources.push(default_source(S::DAY));
+    }
+    if let Err(e) = visuals.check(sources.len()) {
+        eprintln!("{}\n{}", e, usage);
+        exit(2);
+    }
+
+    let mut failed = false;
+    let mut reports: Vec<Report> = vec![];
+    for (i, source) in sources.iter().enumerate() {
+        let name = match source.as_str() {
+            "-" => "stdin",
+            path => path
+        };
+        if sources.len() > 1 {
+            if i > 0 {println!();}
+            println!("{}", name);
+        }
+        let input = match read_source(source) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Error: could not open file '{}': {}", name, e);
+                failed = true;
+                continue;
+            }
+        };
+        let (outcomes, timings) = match measure(|input, options| solve_with::<S>(parse, input, options), input.as_str(), &options) {
+            Ok(solved) => solved,
+            Err(e) => {
+                eprintln!("Error: {}: {}", name, e);
+                failed = true;
+                continue;
+            }
+        };
+        println!("Part 1: {}", outcomes[0]);
+        println!("Part 2: {}", outcomes[1]);
+        if outcomes.iter().any(|o| matches!(o, Outcome::TimedOut | Outcome::Failed(_))) {failed = true;}
+        let report = Report::from_timings(S::DAY, name, &timings);
+        print_timings(&report, &outcomes);
+        reports.push(report);
+        if visuals.render.is_none() && visuals.animate.is_none() {continue;}
+        let parsed = match parse(&input) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                eprintln!("Error: {}: {}", name, e);
+                failed = true;
+                continue;
+            }
+        };
+        if let Some(target) = &visuals.render {
+            if let Err(e) = render_parts::<S>(&parsed, target, &options) {
+                eprintln!("Error: {}", e);
+                failed = true;
+            }
+        }
+        if let Some(dir) = &visuals.animate {
+            if let Err(e) = animate_parts::<S>(&parsed, dir, &visuals, &options) {
+                eprintln!("Error: {}", e);
+                failed = true;
+            }
+        }
+    }
+    if let Err(e) = finish(&reports, &options) {
+        eprintln!("Error: could not write report: {}", e);
+        failed = true;
+    }
+    if failed {exit(1);}
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn take_flag() {
+        let mut options = RunOptions::default();
+        let mut args = ["--bench", "5", "--json", "out.json"].into_iter();
+        while let Some(arg) = args.next() {
+            assert_eq!(options.take_flag(arg, &mut args), Ok(true));
+        }
+        assert_eq!(options.take_flag("--verify", &mut args), Ok(false));
+        assert_eq!(options.bench, Some(5));
+        assert_eq!(options.json, Some(PathBuf::from("out.json")));
+        assert!(options.take_flag("--bench", &mut ["0"].into_iter()).is_err());
+        assert!(options.take_flag("--bench", &mut [].into_iter()).is_err());
+    }
+
+    #[test]
+    fn take_day_flags_() {
+        let args: Vec<String> = ["in.txt", "--json", "--size", "--size", "11x7", "--part", "1"].iter().map(|a| a.to_string()).collect();
+        let mut sizes = vec![];
+        let rest = take_day_flags(args, |flag, args| {
+            if flag != "--size" {return Ok(false);}
+            sizes.extend(args.next().map(|v| v.to_string()));
+            Ok(true)
+        });
+        assert_eq!(rest, Ok(vec!["in.txt".to_string(), "--json".to_string(), "--size".to_string(), "--part".to_string(), "1".to_string()]));
+        assert_eq!(sizes, vec!["11x7"]);
+        assert!(take_day_flags(vec!["--x".to_string()], |_, _| Err("bad".to_string())).is_err());
+    }
+
+    #[test]
+    fn take_flag_part() {
+        let mut options = RunOptions::default();
+        assert!(options.wants(1) && options.wants(2));
+        assert_eq!(options.take_flag("--part", &mut ["2"].into_iter()), Ok(true));
+        assert!(!options.wants(1) && options.wants(2));
+        assert_eq!(options.take_flag("--part", &mut ["both"].into_iter()), Ok(true));
+        assert_eq!(options.part, None);
+        assert!(options.take_flag("--part", &mut ["3"].into_iter()).is_err());
+        assert_eq!(options.take_flag("--timeout", &mut ["1.5"].into_iter()), Ok(true));
+        assert_eq!(options.timeout, Some(Duration::from_millis(1500)));
+        assert!(options.take_flag("--timeout", &mut ["0"].into_iter()).is_err());
+    }
+
+    struct Slow;
+
+    impl Solution for Slow {
+        const DAY: u8 = 0;
+        type Input = u64;
+
+        fn parse(s: &str) -> Result<u64, ParseError> {
+            s.trim().parse().map_err(|_| ParseError::invalid(1, 1, "expected a number"))
+        }
+
+        fn part1(input: &u64) -> Result<Answer, String> {
+            Ok(Answer::from(*input))
+        }
+
+        fn part2(input: &u64) -> Result<Answer, String> {
+            thread::sleep(Duration::from_secs(5));
+            Ok(Answer::from(*input))
+        }
+    }
+
+    struct Failing;
+
+    impl Solution for Failing {
+        const DAY: u8 = 0;
+        type Input = u64;
+
+        fn parse(s: &str) -> Result<u64, ParseError> {
+            Slow::parse(s)
+        }
+
+        fn part1(_input: &u64) -> Result<Answer, String> {
+            Err("no answer".to_string())
+        }
+
+        fn part2(input: &u64) -> Result<Answer, String> {
+            if *input > 0 {panic!("part 2 gave up");}
+            Ok(Answer::from(0))
+        }
+    }
+
+    #[test]
+    fn solve_failed() {
+        let failed = [Outcome::Failed("no answer".to_string()), Outcome::Failed("panicked: part 2 gave up".to_string())];
+        assert_eq!(solve::<Failing>("7", &RunOptions::default()).unwrap().0, failed);
+        let options = RunOptions { timeout: Some(Duration::from_secs(5)), bench: Some(3), ..RunOptions::default() };
+        let (outcomes, timings) = measure(solve::<Failing>, "7", &options).unwrap();
+        assert_eq!(outcomes, failed);
+        assert_eq!(timings.len(), 1);
+    }
+
+    #[test]
+    fn solve_timeout() {
+        let options = RunOptions { timeout: Some(Duration::from_millis(50)), ..RunOptions::default() };
+        let (outcomes, timings) = measure(solve::<Slow>, "7", &options).unwrap();
+        assert_eq!(outcomes, [Outcome::Solved(Answer::from(7u64)), Outcome::TimedOut]);
+        assert_eq!(timings.len(), 1);
+        let options = RunOptions { part: Some(1), ..RunOptions::default() };
+        let (outcomes, _) = solve::<Slow>("7", &options).unwrap();
+        assert_eq!(outcomes, [Outcome::Solved(Answer::from(7u64)), Outcome::Skipped]);
+    }
+
+    #[test]
+    fn visuals_take_flag() {
+        let mut visuals = Visuals::default();
+        let mut args = ["--animate", "out", "--every", "10", "--frames", "png", "--render", "-"].into_iter();
+        while let Some(arg) = args.next() {
+            assert_eq!(visuals.take_flag(arg, &mut args), Ok(true));
+        }
+        assert_eq!(visuals.animate, Some(PathBuf::from("out")));
+        assert_eq!((visuals.every, visuals.frames), (Some(10), FrameFormat::Png));
+        assert_eq!(visuals.render.as_deref(), Some("-"));
+        assert_eq!(visuals.check(1), Ok(()));
+        assert!(visuals.check(2).is_err());
+        assert!(visuals.take_flag("--frames", &mut ["gif"].into_iter()).is_err());
+        assert!(visuals.take_flag("--every", &mut ["0"].into_iter()).is_err());
+        assert_eq!(visuals.take_flag("--bench", &mut ["2"].into_iter()), Ok(false));
+        let visuals = Visuals { every: Some(2), ..Visuals::default() };
+        assert!(visuals.check(1).is_err());
+    }
+
+    #[test]
+    fn part_path_() {
+        assert_eq!(part_path(Path::new("out/maze.png"), 2), PathBuf::from("out/maze.part2.png"));
+        assert_eq!(part_path(Path::new("maze"), 1), PathBuf::from("maze.part1"));
+    }
+
+    #[test]
+    fn default_input_() {
+        assert!(default_input(6).is_some_and(|path| path.ends_with("AoC_12_6/input.txt")));
+        assert_eq!(default_input(99), None);
+        assert_eq!(default_source(99), "-");
+    }
+}
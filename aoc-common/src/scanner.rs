@@ -0,0 +1,268 @@
+use std::{fmt, str::FromStr};
+use crate::error::{ParseError, ParseErrorKind};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ScanError {
+    Eof { pos: usize },
+    Unexpected { pos: usize, expected: String, found: char },
+    BadNumber { pos: usize, text: String }
+}
+
+impl ScanError {
+    pub fn pos(&self) -> usize {
+        match self {
+            ScanError::Eof { pos } => *pos,
+            ScanError::Unexpected { pos, .. } => *pos,
+            ScanError::BadNumber { pos, .. } => *pos
+        }
+    }
+
+    // Turns a byte position in `s` into a line and column.
+    pub fn locate(self, s: &str) -> ParseError {
+        let pos = self.pos();
+        let kind = match self {
+            ScanError::Eof { .. } => ParseErrorKind::Eof { expected: "more input".to_string() },
+            ScanError::Unexpected { expected, found, .. } => ParseErrorKind::Unexpected { expected, found },
+            ScanError::BadNumber { text, .. } => ParseErrorKind::BadNumber { text }
+        };
+        ParseError::at(s, pos, kind)
+    }
+}
+
+impl fmt::Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScanError::Eof { pos } => write!(f, "unexpected end of input at byte {}", pos),
+            ScanError::Unexpected { pos, expected, found } => write!(f, "expected {} at byte {}, found {:?}", expected, pos, found),
+            ScanError::BadNumber { pos, text } => write!(f, "number '{}' at byte {} does not fit", text, pos)
+        }
+    }
+}
+
+impl std::error::Error for ScanError {}
+
+// A cursor over the input. Every scan either consumes what it matched and
+// returns Ok, or leaves the cursor where it was and returns Err.
+#[derive(Debug, Clone)]
+pub struct Scanner<'a> {
+    s: &'a str,
+    pos: usize
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(s: &'a str) -> Self {
+        Self {
+            s,
+            pos: 0
+        }
+    }
+
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+
+    pub fn seek(&mut self, pos: usize) {
+        self.pos = pos.min(self.s.len());
+    }
+
+    pub fn at_end(&self) -> bool {
+        self.pos >= self.s.len()
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.s[self.pos..]
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    pub fn next_char(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    pub fn char(&mut self, c: char) -> Result<(), ScanError> {
+        match self.peek() {
+            None => Err(ScanError::Eof { pos: self.pos }),
+            Some(found) if found == c => {
+                self.pos += c.len_utf8();
+                Ok(())
+            },
+            Some(found) => Err(self.unexpected(format!("{:?}", c), found))
+        }
+    }
+
+    pub fn literal(&mut self, lit: &str) -> Result<(), ScanError> {
+        if self.rest().starts_with(lit) {
+            self.pos += lit.len();
+            return Ok(());
+        }
+        let matched = self.rest().chars().zip(lit.chars()).take_while(|(a, b)| a == b).count();
+        let at = self.pos + self.rest().chars().take(matched).map(|c| c.len_utf8()).sum::<usize>();
+        match self.s[at..].chars().next() {
+            None => Err(ScanError::Eof { pos: at }),
+            Some(found) => Err(ScanError::Unexpected { pos: at, expected: format!("{:?}", lit), found })
+        }
+    }
+
+    pub fn num<T: FromStr>(&mut self) -> Result<T, ScanError> {
+        let digits = self.rest().bytes().take_while(|b| b.is_ascii_digit()).count();
+        if digits == 0 {
+            return Err(self.unexpected_here("a digit"));
+        }
+        let text = &self.rest()[..digits];
+        let x = text.parse().map_err(|_| ScanError::BadNumber { pos: self.pos, text: text.to_string() })?;
+        self.pos += digits;
+        Ok(x)
+    }
+
+    pub fn signed<T: FromStr>(&mut self) -> Result<T, ScanError> {
+        let sign = match self.peek() {
+            Some('-') | Some('+') => 1,
+            _ => 0
+        };
+        let digits = self.rest().bytes().skip(sign).take_while(|b| b.is_ascii_digit()).count();
+        if digits == 0 {
+            let start = self.pos;
+            self.pos += sign;
+            let err = self.unexpected_here("a digit");
+            self.pos = start;
+            return Err(err);
+        }
+        let text = &self.rest()[..sign + digits];
+        let x = text.parse().map_err(|_| ScanError::BadNumber { pos: self.pos, text: text.to_string() })?;
+        self.pos += sign + digits;
+        Ok(x)
+    }
+
+    pub fn skip_spaces(&mut self) -> usize {
+        let n = self.rest().bytes().take_while(|&b| b == b' ' || b == b'\t').count();
+        self.pos += n;
+        n
+    }
+
+    pub fn skip_whitespace(&mut self) -> usize {
+        let n = self.rest().bytes().take_while(|b| b.is_ascii_whitespace()).count();
+        self.pos += n;
+        n
+    }
+
+    pub fn line_ending(&mut self) -> Result<(), ScanError> {
+        if self.rest().starts_with("\r\n") {
+            self.pos += 2;
+            Ok(())
+        } else if self.rest().starts_with('\n') {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.unexpected_here("a line ending"))
+        }
+    }
+
+    pub fn skip_line(&mut self) {
+        match self.rest().find('\n') {
+            Some(i) => {self.pos += i + 1;},
+            None => {self.pos = self.s.len();}
+        }
+    }
+
+    fn unexpected_here(&self, expected: &str) -> ScanError {
+        match self.peek() {
+            None => ScanError::Eof { pos: self.pos },
+            Some(found) => self.unexpected(expected.to_string(), found)
+        }
+    }
+
+    fn unexpected(&self, expected: String, found: char) -> ScanError {
+        ScanError::Unexpected { pos: self.pos, expected, found }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn num_ok() {
+        let mut sc = Scanner::new("sdfi2if");
+        sc.seek(4);
+        assert_eq!(sc.num::<i32>(), Ok(2));
+        assert_eq!(sc.pos(), 5);
+    }
+
+    #[test]
+    fn num_err() {
+        let mut sc = Scanner::new("sdfi2if");
+        sc.seek(3);
+        assert_eq!(sc.num::<i32>(), Err(ScanError::Unexpected { pos: 3, expected: "a digit".to_string(), found: 'i' }));
+        assert_eq!(sc.pos(), 3);
+    }
+
+    #[test]
+    fn num_overflow() {
+        let mut sc = Scanner::new("300,");
+        assert_eq!(sc.num::<u8>(), Err(ScanError::BadNumber { pos: 0, text: "300".to_string() }));
+        assert_eq!(sc.num::<u64>(), Ok(300));
+    }
+
+    #[test]
+    fn signed_() {
+        let mut sc = Scanner::new("-12,+7,-x");
+        assert_eq!(sc.signed::<i16>(), Ok(-12));
+        sc.char(',').unwrap();
+        assert_eq!(sc.signed::<i16>(), Ok(7));
+        sc.char(',').unwrap();
+        assert!(sc.signed::<i16>().is_err());
+        assert_eq!(sc.pos(), 7);
+    }
+
+    #[test]
+    fn char_() {
+        let mut sc = Scanner::new("ab");
+        assert!(sc.char('b').is_err());
+        assert_eq!(sc.char('a'), Ok(()));
+        assert_eq!(sc.char('b'), Ok(()));
+        assert_eq!(sc.char('c'), Err(ScanError::Eof { pos: 2 }));
+    }
+
+    #[test]
+    fn literal_() {
+        let mut sc = Scanner::new("don't()do()");
+        assert_eq!(sc.literal("do()"), Err(ScanError::Unexpected { pos: 2, expected: "\"do()\"".to_string(), found: 'n' }));
+        assert_eq!(sc.pos(), 0);
+        assert_eq!(sc.literal("don't()"), Ok(()));
+        assert_eq!(sc.literal("do()"), Ok(()));
+        assert!(sc.at_end());
+    }
+
+    #[test]
+    fn line_ending_() {
+        let mut sc = Scanner::new("1\r\n2\n\n3");
+        assert_eq!(sc.num::<u8>(), Ok(1));
+        assert_eq!(sc.line_ending(), Ok(()));
+        assert_eq!(sc.num::<u8>(), Ok(2));
+        assert_eq!(sc.line_ending(), Ok(()));
+        assert_eq!(sc.line_ending(), Ok(()));
+        assert!(sc.line_ending().is_err());
+        assert_eq!(sc.rest(), "3");
+    }
+
+    #[test]
+    fn whitespace() {
+        let mut sc = Scanner::new(" \t 4 \n 5");
+        assert_eq!(sc.skip_spaces(), 3);
+        assert_eq!(sc.num::<u8>(), Ok(4));
+        assert_eq!(sc.skip_whitespace(), 3);
+        assert_eq!(sc.num::<u8>(), Ok(5));
+    }
+
+    #[test]
+    fn locate() {
+        let mut sc = Scanner::new("12\n3x");
+        sc.seek(3);
+        let e = sc.num::<u8>().and_then(|_| sc.char(',')).unwrap_err().locate("12\n3x");
+        assert_eq!((e.line, e.column, e.found()), (2, 2, Some('x')));
+    }
+}
@@ -0,0 +1,23 @@
+use crate::{render::Frame, Answer, ParseError};
+
+pub trait Solution {
+    const DAY: u8;
+    type Input;
+
+    fn parse(s: &str) -> Result<Self::Input, ParseError>;
+    // An Err says why the input has no answer, such as a program that never
+    // halts.
+    fn part1(input: &Self::Input) -> Result<Answer, String>;
+    fn part2(input: &Self::Input) -> Result<Answer, String>;
+
+    // A picture of the puzzle once `part` is solved, for days that have one.
+    fn render(_input: &Self::Input, _part: u8) -> Option<Frame> {
+        None
+    }
+
+    // Passes `frame` a picture of each step of the simulation behind `part`.
+    // Days that are not step simulations return false.
+    fn animate(_input: &Self::Input, _part: u8, _frame: &mut dyn FnMut(&Frame)) -> bool {
+        false
+    }
+}
@@ -0,0 +1,25 @@
+use std::{env, fs, path::Path};
+
+// Writes one test per examples/dayNN/<name>.txt for tests/examples.rs.
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+    println!("cargo:rerun-if-changed={}", root.display());
+    let mut tests = String::new();
+    let mut dirs: Vec<_> = fs::read_dir(&root).map(|d| d.flatten().map(|e| e.path()).collect()).unwrap_or_default();
+    dirs.sort();
+    for dir in dirs.iter() {
+        let day = match dir.file_name().and_then(|n| n.to_str()).and_then(|n| n.strip_prefix("day")) {
+            Some(n) => n.parse::<u8>().unwrap_or_else(|_| panic!("bad example directory {}", dir.display())),
+            None => {continue;}
+        };
+        let mut files: Vec<_> = fs::read_dir(dir).unwrap().flatten().map(|e| e.path()).collect();
+        files.sort();
+        for file in files.iter().filter(|f| f.extension().is_some_and(|e| e == "txt")) {
+            let stem = file.file_stem().unwrap().to_string_lossy();
+            let name: String = stem.chars().map(|c| if c.is_ascii_alphanumeric() {c.to_ascii_lowercase()} else {'_'}).collect();
+            tests.push_str(&format!("#[test]\nfn day{:02}_{}() {{\n    check::<aoc_12_{}::Day{:02}>({:?});\n}}\n\n",
+                day, name, day, day, file.display().to_string()));
+        }
+    }
+    fs::write(Path::new(&env::var("OUT_DIR").unwrap()).join("examples.rs"), tests).unwrap();
+}
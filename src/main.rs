@@ -0,0 +1,221 @@
+use std::{env, path::{Path, PathBuf}, process::exit};
+use aoc_common::{bench::Report, runner::{self, default_input, find_in_workspace, read_input, read_source, Outcome, RunOptions, SolveFn}, Answers, Verdict};
+
+const DAYS: [SolveFn; 17] = [
+    runner::solve::<aoc_12_1::Day01>,
+    runner::solve::<aoc_12_2::Day02>,
+    runner::solve::<aoc_12_3::Day03>,
+    runner::solve::<aoc_12_4::Day04>,
+    runner::solve::<aoc_12_5::Day05>,
+    runner::solve::<aoc_12_6::Day06>,
+    runner::solve::<aoc_12_7::Day07>,
+    runner::solve::<aoc_12_8::Day08>,
+    runner::solve::<aoc_12_9::Day09>,
+    runner::solve::<aoc_12_10::Day10>,
+    runner::solve::<aoc_12_11::Day11>,
+    runner::solve::<aoc_12_12::Day12>,
+    runner::solve::<aoc_12_13::Day13>,
+    runner::solve::<aoc_12_14::Day14>,
+    runner::solve::<aoc_12_15::Day15>,
+    runner::solve::<aoc_12_16::Day16>,
+    runner::solve::<aoc_12_17::Day17>,
+];
+
+const USAGE: &str = "Usage: aoc run <day | first..last | all> [--verify [answers.toml]] [--part 1|2|both] [--timeout SECS] [--bench N] [--json FILE]
+A part that times out cannot be stopped and keeps using a CPU until aoc exits,
+so the timings of the days run after it may be slower.";
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    days: Vec<u8>,
+    verify: Option<PathBuf>,
+    run: RunOptions
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.iter().any(|a| a == "--help" || a == "-h") {
+        println!("{}", USAGE);
+        exit(0);
+    }
+    let options = parse_args(&args).unwrap_or_else(|e| {
+        eprintln!("{}\n{}", e, USAGE);
+        exit(2);
+    });
+    let answers = options.verify.as_ref().map(|path| {
+        let s = read_input(path).unwrap_or_else(|e| {
+            eprintln!("Error: could not read '{}': {}", path.display(), e);
+            exit(1);
+        });
+        Answers::parse(&s).unwrap_or_else(|e| {
+            eprintln!("Error: {}: {}", path.display(), e);
+            exit(1);
+        })
+    });
+
+    let mut failed = false;
+    let mut tally = [0; 3];
+    let mut reports: Vec<Report> = vec![];
+    let single = options.days.len() == 1;
+    for day in options.days {
+        println!("Day {}", day);
+        // With a single day and no input file, the input comes from stdin.
+        let path = match default_input(day) {
+            Some(path) => path,
+            None if single => PathBuf::from("-"),
+            None => {
+                eprintln!("Error: no AoC_12_{}/input.txt here or above", day);
+                failed = true;
+                continue;
+            }
+        };
+        let input = match read_source(&path.display().to_string()) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Error: could not read '{}': {}", path.display(), e);
+                failed = true;
+                continue;
+            }
+        };
+        let (outcomes, timings) = match runner::measure(DAYS[day as usize - 1], input.as_str(), &options.run) {
+            Ok(solved) => solved,
+            Err(e) => {
+                eprintln!("Error: {}: {}", path.display(), e);
+                failed = true;
+                continue;
+            }
+        };
+        for (part, outcome) in (1..).zip(outcomes.iter()) {
+            match (outcome, &answers) {
+                (Outcome::Skipped, _) => {},
+                (Outcome::TimedOut | Outcome::Failed(_), _) => {
+                    println!("Part {}: {}", part, outcome);
+                    tally[1] += 1;
+                    failed = true;
+                },
+                (Outcome::Solved(answer), None) => println!("Part {}: {}", part, answer),
+                (Outcome::Solved(answer), Some(answers)) => {
+                    let verdict = answers.check(day, part, answer);
+                    println!("Part {}: {} ({})", part, answer, verdict);
+                    match verdict {
+                        Verdict::Pass => {tally[0] += 1;},
+                        Verdict::Fail(_) => {
+                            tally[1] += 1;
+                            failed = true;
+                        },
+                        Verdict::Unknown => {tally[2] += 1;}
+                    }
+                }
+            }
+        }
+        let report = Report::from_timings(day, &path.display().to_string(), &timings);
+        runner::print_timings(&report, &outcomes);
+        reports.push(report);
+    }
+    if let Err(e) = runner::finish(&reports, &options.run) {
+        eprintln!("Error: could not write report: {}", e);
+        failed = true;
+    }
+    if answers.is_some() {
+        println!("{} passed, {} failed, {} unknown", tally[0], tally[1], tally[2]);
+    }
+    if failed {exit(1);}
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut args = args.iter().map(|a| a.as_str()).peekable();
+    if args.next() != Some("run") {
+        return Err("expected 'run'".to_string());
+    }
+    let days = parse_days(args.next().ok_or("missing day")?)?;
+    let mut verify = None;
+    let mut run = RunOptions::default();
+    while let Some(arg) = args.next() {
+        if run.take_flag(arg, &mut args)? {continue;}
+        match arg {
+            "--verify" => {
+                let path = match args.peek() {
+                    Some(next) if !next.starts_with("--") => Path::new(args.next().unwrap()).to_path_buf(),
+                    _ => find_in_workspace(Path::new("answers.toml")).unwrap_or_else(|| PathBuf::from("answers.toml"))
+                };
+                verify = Some(path);
+            },
+            _ => {return Err(format!("unknown argument '{}'", arg));}
+        }
+    }
+    Ok(Options { days, verify, run })
+}
+
+fn parse_days(spec: &str) -> Result<Vec<u8>, String> {
+    if spec == "all" {
+        return Ok((1..=DAYS.len() as u8).collect());
+    }
+    let (first, last) = match spec.split_once("..") {
+        Some((first, last)) => (parse_day(first)?, parse_day(last.trim_start_matches('='))?),
+        None => {
+            let day = parse_day(spec)?;
+            (day, day)
+        }
+    };
+    if first > last {
+        return Err(format!("empty day range '{}'", spec));
+    }
+    Ok((first..=last).collect())
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    match s.parse::<u8>() {
+        Ok(day) if day >= 1 && day as usize <= DAYS.len() => Ok(day),
+        _ => Err(format!("expected a day from 1 to {}, found '{}'", DAYS.len(), s))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_days_single() {
+        assert_eq!(parse_days("6"), Ok(vec![6]));
+    }
+
+    #[test]
+    fn parse_days_range() {
+        assert_eq!(parse_days("3..5"), Ok(vec![3, 4, 5]));
+        assert_eq!(parse_days("3..=5"), Ok(vec![3, 4, 5]));
+        assert_eq!(parse_days("1..17").unwrap().len(), 17);
+    }
+
+    #[test]
+    fn parse_days_all() {
+        assert_eq!(parse_days("all"), Ok((1..=17).collect()));
+    }
+
+    #[test]
+    fn parse_args_() {
+        let args = |s: &str| s.split(' ').map(|a| a.to_string()).collect::<Vec<_>>();
+        assert_eq!(parse_args(&args("run 3")), Ok(Options { days: vec![3], verify: None, run: RunOptions::default() }));
+        let options = parse_args(&args("run all --verify")).unwrap();
+        assert!(options.verify.unwrap().ends_with("answers.toml"));
+        let options = parse_args(&args("run 1..2 --verify mine.toml")).unwrap();
+        assert_eq!(options.verify, Some(PathBuf::from("mine.toml")));
+        let options = parse_args(&args("run 6 --bench 5 --verify --json out.json")).unwrap();
+        assert_eq!(options.run.bench, Some(5));
+        assert!(options.verify.is_some());
+        assert_eq!(options.run.json, Some(PathBuf::from("out.json")));
+        let options = parse_args(&args("run 17 --part 1 --timeout 2")).unwrap();
+        assert_eq!(options.run.part, Some(1));
+        assert!(options.run.timeout.is_some());
+        assert!(parse_args(&args("run 3 --frobnicate")).is_err());
+        assert!(parse_args(&args("run 3 --bench")).is_err());
+        assert!(parse_args(&args("3")).is_err());
+    }
+
+    #[test]
+    fn parse_days_err() {
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("18").is_err());
+        assert!(parse_days("5..3").is_err());
+        assert!(parse_days("x").is_err());
+    }
+}
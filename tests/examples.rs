@@ -0,0 +1,26 @@
+use std::path::Path;
+use aoc_common::{runner::read_input, Answers, Solution};
+
+// Runs every part that has an expected answer beside the example.
+fn check<S: Solution>(example: &str) {
+    let path = Path::new(example);
+    let expected_path = path.with_extension("toml");
+    let expected = read_input(&expected_path).unwrap_or_else(|e| panic!("could not read {}: {}", expected_path.display(), e));
+    let expected = Answers::parse_day(S::DAY, &expected).unwrap_or_else(|e| panic!("{}: {}", expected_path.display(), e));
+    let input = read_input(path).unwrap();
+    let parsed = S::parse(&input).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+
+    let mut checked = 0;
+    for part in 1..=2 {
+        let Some(answer) = expected.get(S::DAY, part) else {continue;};
+        let got = match part {
+            1 => S::part1(&parsed),
+            _ => S::part2(&parsed)
+        }.unwrap_or_else(|e| panic!("{} part {}: {}", path.display(), part, e));
+        assert_eq!(got.to_string(), answer.to_string(), "{} part {}", path.display(), part);
+        checked += 1;
+    }
+    assert!(checked > 0, "{} has no expected answers", expected_path.display());
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));